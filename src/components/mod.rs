@@ -1,3 +1,5 @@
 pub mod card;
+pub mod modal;
 pub mod search;
+pub mod status;
 pub mod table;
@@ -0,0 +1,19 @@
+use dioxus::prelude::*;
+
+#[component]
+pub fn Modal(title: String, open: Signal<bool>, children: Element, footer: Option<Element>) -> Element {
+    rsx! {
+        if open() {
+            div { class: "popup-task-overlay",
+                div { class: "popup-task",
+                    h2 { "{title}" }
+                    {children}
+                    div { class: "buttons",
+                        {footer}
+                        button { class: "close", onclick: move |_| open.set(false), "Close" }
+                    }
+                }
+            }
+        }
+    }
+}
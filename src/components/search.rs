@@ -1,11 +1,16 @@
 use dioxus::prelude::*;
 
+use crate::utils::parse_datetime_local;
+use crate::utils::preferences::use_preferences;
+use crate::utils::zone_label;
+
 pub trait SearchSelectLike: Sized {
     fn raw_options() -> Vec<Self>;
 
     fn to_string(it: &Self) -> String;
 
-    fn from_string(it: String) -> Self;
+    /// `None` for strings that aren't one of the options.
+    fn from_string(it: String) -> Option<Self>;
 
     fn all() -> &'static str {
         "All"
@@ -22,6 +27,16 @@ pub trait SearchSelectLike: Sized {
     fn read(it: &Option<Self>) -> String {
         it.as_ref().map(Self::to_string).unwrap_or(Self::all().to_string())
     }
+
+    /// Parses a value kept in the URL, unknown values select all.
+    fn from_query(it: &str) -> Option<Self> {
+        Self::raw_options().into_iter().find(|opt| Self::to_string(opt) == it)
+    }
+
+    /// The URL form of a selection, empty when all are selected.
+    fn to_query(it: &Option<Self>) -> String {
+        it.as_ref().map(Self::to_string).unwrap_or_default()
+    }
 }
 
 trait SearchSelectRender: SearchSelectLike {
@@ -48,7 +63,7 @@ trait SearchSelectSignals: SearchSelectLike {
         signal.set(if str == Self::all() {
             None
         } else {
-            Some(Self::from_string(str))
+            Self::from_string(str)
         })
     }
 }
@@ -114,6 +129,11 @@ pub fn Search<
                     oninput: move |event| async move {
                         input.set(Some(event.value()));
                     },
+                    onkeydown: move |event| {
+                        if event.key() == Key::Enter {
+                            trigger.set(true);
+                        }
+                    },
                 }
                 button {
                     onclick: move |_| async move {
@@ -125,3 +145,51 @@ pub fn Search<
         }
     }
 }
+
+/// Values are read in the zone timestamps are shown in, which is named next to the inputs.
+#[component]
+pub fn DateRangePicker(after: Signal<Option<String>>, before: Signal<Option<String>>) -> Element {
+    let zone = use_preferences().read().time.time_zone;
+    let errors = [("Submitted after", after()), ("Submitted before", before())]
+        .into_iter()
+        .filter_map(|(label, value)| {
+            let e = parse_datetime_local(value.as_deref()?, zone).err()?;
+            Some(format!("{label}: {e}, the bound isn't applied"))
+        })
+        .collect::<Vec<_>>();
+    rsx! {
+        div { id: "search-range",
+            label {
+                "Submitted after"
+                input {
+                    r#type: "datetime-local",
+                    value: after().unwrap_or_default(),
+                    max: before().unwrap_or_default(),
+                    onchange: move |evt| after.set(Some(evt.value()).filter(|it| !it.is_empty())),
+                }
+            }
+            label {
+                "Submitted before"
+                input {
+                    r#type: "datetime-local",
+                    value: before().unwrap_or_default(),
+                    min: after().unwrap_or_default(),
+                    onchange: move |evt| before.set(Some(evt.value()).filter(|it| !it.is_empty())),
+                }
+            }
+            span { class: "search-range-zone", "Times in {zone_label(zone)}" }
+            if after().is_some() || before().is_some() {
+                button {
+                    onclick: move |_| {
+                        after.set(None);
+                        before.set(None);
+                    },
+                    "Clear dates"
+                }
+            }
+        }
+        for e in errors {
+            div { class: "search-range-error", role: "alert", "{e}" }
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use dioxus::prelude::*;
+
+#[component]
+pub fn Spinner() -> Element {
+    rsx! {
+        div { class: "loading", role: "status",
+            div { class: "spinner" }
+            "Loading..."
+        }
+    }
+}
+
+#[component]
+pub fn ErrorCard(message: String, onretry: EventHandler<MouseEvent>) -> Element {
+    rsx! {
+        div { class: "error-card", role: "alert",
+            div { "Failed to load: {message}" }
+            button { class: "action-button", onclick: onretry, "Retry" }
+        }
+    }
+}
+
+#[component]
+pub fn NotFound(message: String) -> Element {
+    rsx! {
+        div { class: "error-card", "{message}" }
+    }
+}
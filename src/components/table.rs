@@ -1,10 +1,22 @@
 use dioxus::prelude::*;
 use serde::Serialize;
+use std::collections::HashSet;
 use std::future::Future;
 use std::pin::Pin;
+use std::rc::Rc;
 use zkp_service_helper::interface::PaginationResult;
 
 use crate::components::card::EntryLike;
+use crate::components::status::ErrorCard;
+use crate::components::status::Spinner;
+use crate::utils::export::TableFormat;
+use crate::utils::live::use_live_tick;
+use crate::utils::preferences::use_preferences;
+use crate::utils::service::use_zk;
+use crate::utils::service::ZkClient;
+use crate::utils::Loadable;
+use crate::utils::Query;
+use crate::utils::SortKey;
 use crate::utils::ZkEntry;
 use crate::GLOBAL_PADDING;
 
@@ -12,29 +24,298 @@ pub trait TableLike {
     fn title(&self) -> &str;
     fn headers(&self) -> Vec<&str>;
     fn rows(&self) -> Vec<Vec<ZkEntry>>;
+
+    /// Identifies each row so live refreshes can highlight rows that were not there before.
+    fn row_keys(&self) -> Vec<String> {
+        vec![]
+    }
+
+    /// What `entry` sorts by in `column`, columns without any key can't be sorted.
+    fn sort_key(&self, column: usize, entry: &ZkEntry) -> Option<SortKey> {
+        let _ = column;
+        entry.sort_key()
+    }
+
+    /// Shown above the rows, e.g. when they are known to be incomplete.
+    fn notice(&self) -> Option<String> {
+        None
+    }
+}
+
+/// A page of rows renders as the rows it holds.
+impl<T: TableLike> TableLike for PaginationResult<T> {
+    fn title(&self) -> &str {
+        self.data.title()
+    }
+
+    fn headers(&self) -> Vec<&str> {
+        self.data.headers()
+    }
+
+    fn rows(&self) -> Vec<Vec<ZkEntry>> {
+        self.data.rows()
+    }
+
+    fn row_keys(&self) -> Vec<String> {
+        self.data.row_keys()
+    }
+
+    fn sort_key(&self, column: usize, entry: &ZkEntry) -> Option<SortKey> {
+        self.data.sort_key(column, entry)
+    }
+
+    fn notice(&self) -> Option<String> {
+        self.data.notice()
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+struct SortBy {
+    column: usize,
+    descending: bool,
+}
+
+impl SortBy {
+    fn toggle(current: Option<Self>, column: usize) -> Self {
+        match current {
+            Some(it) if it.column == column => Self { column, descending: !it.descending },
+            _ => Self { column, descending: false },
+        }
+    }
+
+    fn indicator(current: Option<Self>, column: usize) -> &'static str {
+        match current {
+            Some(it) if it.column == column && it.descending => " \u{25BC}",
+            Some(it) if it.column == column => " \u{25B2}",
+            _ => "",
+        }
+    }
+
+    /// The `aria-sort` value of a sortable header.
+    fn aria(current: Option<Self>, column: usize) -> &'static str {
+        match current {
+            Some(it) if it.column == column && it.descending => "descending",
+            Some(it) if it.column == column => "ascending",
+            _ => "none",
+        }
+    }
+}
+
+/// "All pages" exports stop after this many rows, tables with more ask before exporting the first ones.
+const EXPORT_ROW_LIMIT: u64 = 10_000;
+
+/// Loads the rows of every page for an export, up to `EXPORT_ROW_LIMIT`, counting the rows loaded so far in `loaded`.
+#[derive(Clone)]
+pub struct FetchAllRows {
+    /// Rows on the backend when the table last loaded.
+    total: u64,
+    fetch: Rc<dyn Fn(Signal<u64>) -> Pin<Box<dyn Future<Output = anyhow::Result<Vec<Vec<ZkEntry>>>>>>>,
+}
+
+impl PartialEq for FetchAllRows {
+    fn eq(&self, other: &Self) -> bool {
+        self.total == other.total && Rc::ptr_eq(&self.fetch, &other.fetch)
+    }
+}
+
+#[component]
+fn TableExportMenu(
+    title: String,
+    headers: Vec<String>,
+    page: Vec<Vec<ZkEntry>>,
+    fetch_all: Option<FetchAllRows>,
+) -> Element {
+    let mut open = use_signal(|| false);
+    let mut walk = use_signal(|| Option::<Task>::None);
+    let mut loaded = use_signal(|| 0u64);
+    let mut confirm = use_signal(|| Option::<TableFormat>::None);
+    let mut error = use_signal(|| Option::<String>::None);
+
+    let formats = [TableFormat::Csv, TableFormat::Json];
+    let export = move |format: TableFormat, suffix: &str, rows: &[Vec<ZkEntry>]| {
+        let headers = headers.iter().map(String::as_str).collect::<Vec<_>>();
+        let res = format
+            .export(&title, suffix, &headers, rows)
+            .inspect_err(|e| tracing::error!("{e}"));
+        error.set(res.err().map(|e| e.to_string()));
+    };
+    // Walks the pages in a task, cancelling drops it along with the request in flight
+    let export_all = {
+        let (export, fetch_all) = (export.clone(), fetch_all.clone());
+        move |format: TableFormat| {
+            let Some(fetch_all) = fetch_all.clone() else {
+                return;
+            };
+            let mut export = export.clone();
+            loaded.set(0);
+            let rows = (fetch_all.fetch)(loaded);
+            walk.set(Some(spawn(async move {
+                match rows.await {
+                    Ok(rows) => export(format, "all", &rows),
+                    Err(e) => error.set(Some(e.to_string())),
+                }
+                walk.set(None);
+            })));
+        }
+    };
+    let expected = fetch_all.as_ref().map(|it| it.total.min(EXPORT_ROW_LIMIT)).unwrap_or_default();
+
+    rsx! {
+        div { class: "export-menu",
+            if walk().is_some() {
+                span { class: "export-progress", "Exporting {loaded} of {expected} rows" }
+                button {
+                    class: "action-button",
+                    onclick: move |_| {
+                        if let Some(task) = walk.take() {
+                            task.cancel();
+                        }
+                    },
+                    "Cancel"
+                }
+            } else {
+                button { class: "action-button", onclick: move |_| open.toggle(), "Export" }
+            }
+            if open() {
+                div { class: "export-menu-items",
+                    {formats.into_iter().map(|format| {
+                        let page = page.clone();
+                        let mut export = export.clone();
+                        rsx! {
+                            button {
+                                onclick: move |_| {
+                                    open.set(false);
+                                    export(format, "page", &page);
+                                },
+                                "Current page as {format.label()}"
+                            }
+                        }
+                    })}
+                    if let Some(fetch_all) = fetch_all {
+                        {formats.into_iter().map(|format| {
+                            let mut export_all = export_all.clone();
+                            let total = fetch_all.total;
+                            rsx! {
+                                button {
+                                    onclick: move |_| {
+                                        open.set(false);
+                                        if total > EXPORT_ROW_LIMIT {
+                                            confirm.set(Some(format));
+                                        } else {
+                                            export_all(format);
+                                        }
+                                    },
+                                    "All pages as {format.label()}"
+                                }
+                            }
+                        })}
+                    }
+                }
+            }
+            if let Some(format) = confirm() {
+                div { class: "export-menu-items",
+                    div { class: "export-menu-heading",
+                        "This table has more than {EXPORT_ROW_LIMIT} rows, only the first {EXPORT_ROW_LIMIT} are exported."
+                    }
+                    button {
+                        onclick: {
+                            let mut export_all = export_all.clone();
+                            move |_| {
+                                confirm.set(None);
+                                export_all(format);
+                            }
+                        },
+                        "Export the first {EXPORT_ROW_LIMIT} rows as {format.label()}"
+                    }
+                    button { onclick: move |_| confirm.set(None), "Cancel" }
+                }
+            }
+            if let Some(e) = error() {
+                div { class: "download-error", "{e}" }
+            }
+        }
+    }
 }
 
 #[component]
-pub fn Table<T: TableLike + PartialEq + Clone + 'static>(data: T, pagination: Option<PaginationHandler>) -> Element {
+pub fn Table<T: TableLike + PartialEq + Clone + 'static>(
+    data: T,
+    pagination: Option<PaginationHandler>,
+    #[props(default)] highlighted: HashSet<String>,
+    fetch_all: Option<FetchAllRows>,
+) -> Element {
     let title = data.title();
+    let notice = data.notice();
     let headers = data.headers();
     let n = headers.len();
+    let mut sort = use_signal(|| Option::<SortBy>::None);
     let rows = data.rows();
+    let mut keys = data.row_keys();
+    keys.resize(rows.len(), String::new());
+    let sortable = (0..n)
+        .map(|col| {
+            rows.iter()
+                .any(|row| row.get(col).is_some_and(|it| data.sort_key(col, it).is_some()))
+        })
+        .collect::<Vec<_>>();
+
+    // Sorts the rows already loaded, only the current page of a paginated table, rows without a key stay at the bottom
+    // in either direction
+    let paginated = pagination.is_some();
+    let mut rows = rows.into_iter().zip(keys).collect::<Vec<_>>();
+    if let Some(SortBy { column, descending }) = sort() {
+        let key = |row: &Vec<ZkEntry>| row.get(column).and_then(|it| data.sort_key(column, it));
+        rows.sort_by(|(a, _), (b, _)| match (key(a), key(b)) {
+            (Some(a), Some(b)) if descending => b.cmp(&a),
+            (Some(a), Some(b)) => a.cmp(&b),
+            (a, b) => a.is_none().cmp(&b.is_none()),
+        });
+    }
+
+    let export_headers = headers.iter().map(|it| it.to_string()).collect::<Vec<_>>();
+    let export_page = rows.iter().map(|(row, _)| row.clone()).collect::<Vec<_>>();
 
     rsx! {
         div { style: GLOBAL_PADDING,
-            h3 { "{title}" }
+            div { class: "table-title",
+                h3 { "{title}" }
+                TableExportMenu {
+                    title: title.to_string(),
+                    headers: export_headers,
+                    page: export_page,
+                    fetch_all,
+                }
+            }
+            if let Some(notice) = notice {
+                div { class: "table-notice", "{notice}" }
+            }
             table { style: "border-collapse: collapse; width: 100%;",
                 thead {
                     tr {
-                        {headers.into_iter().map(|it| rsx! {
-                            th { class: "table-row table-header-color", "{it}" }
+                        {headers.into_iter().enumerate().map(|(col, it)| rsx! {
+                            if sortable[col] {
+                                th {
+                                    class: "table-row table-header-color table-header-sortable",
+                                    "aria-sort": SortBy::aria(sort(), col),
+                                    button {
+                                        class: "table-sort-button",
+                                        title: if paginated { "Sorts the rows on this page" },
+                                        onclick: move |_| sort.set(Some(SortBy::toggle(sort(), col))),
+                                        "{it}"
+                                        if sort().is_some_and(|it| it.column == col) {
+                                            span { "aria-hidden": "true", "{SortBy::indicator(sort(), col)}" }
+                                        }
+                                    }
+                                }
+                            } else {
+                                th { class: "table-row table-header-color", "{it}" }
+                            }
                         })}
                     }
                 }
                 tbody {
-                    {rows.into_iter().enumerate().map(|(i, row)| rsx! {
-                        tr {
+                    {rows.into_iter().enumerate().map(|(i, (row, key))| rsx! {
+                        tr { class: if highlighted.contains(&key) { "table-row-new" },
                             {row.into_iter().map(|entry| rsx! {
                                 td { class: format!("table-row table-row-{}-color", if i % 2 != 0 { "even" } else { "odd" }),
                                     {entry.into_cell()}
@@ -43,17 +324,11 @@ pub fn Table<T: TableLike + PartialEq + Clone + 'static>(data: T, pagination: Op
                         }
                     })}
                 }
-                if let Some(pagn) = pagination {
+                if let Some(handler) = pagination {
                     tfoot {
                         tr {
                             td { colspan: "{n}",
-                                div { class: "table-row table-header-color pagination-button",
-                                    button { onclick: pagn.begin, "<<" }
-                                    button { onclick: pagn.prev, "<" }
-                                    "{pagn.curr + 1} of {pagn.total + 1}"
-                                    button { onclick: pagn.next, ">" }
-                                    button { onclick: pagn.last, ">>" }
-                                }
+                                PaginationControls { handler }
                             }
                         }
                     }
@@ -63,101 +338,302 @@ pub fn Table<T: TableLike + PartialEq + Clone + 'static>(data: T, pagination: Op
     }
 }
 
-#[derive(Clone, PartialEq)]
+pub const PAGE_SIZES: [u64; 5] = [5, 10, 25, 50, 100];
+
+/// Page state of a paginated table, `curr` is the 0-based page and `total` the number of rows on the backend.
+#[derive(Clone, Copy, PartialEq)]
 pub struct PaginationHandler {
-    begin: EventHandler<MouseEvent>,
-    last: EventHandler<MouseEvent>,
-    prev: EventHandler<MouseEvent>,
-    next: EventHandler<MouseEvent>,
-    curr: u64,
+    curr: Signal<u64>,
+    per: Signal<u64>,
     total: u64,
 }
 
 impl PaginationHandler {
-    pub fn new(
-        begin: EventHandler<MouseEvent>,
-        last: EventHandler<MouseEvent>,
-        prev: EventHandler<MouseEvent>,
-        next: EventHandler<MouseEvent>,
-        curr: u64,
-        total: u64,
-    ) -> Self {
-        Self { begin, last, prev, next, curr, total }
-    }
-
-    pub fn default(total: u64, mut curr: Signal<u64>, n_paginated: Option<u64>) -> Self {
-        let n_pagn = n_paginated.unwrap_or(10);
-        let total_p = total / n_pagn;
-
-        PaginationHandler::new(
-            EventHandler::new(move |_| {
-                curr.set(0);
-            }),
-            EventHandler::new(move |_| {
-                curr.set(total_p);
-            }),
-            EventHandler::new(move |_| {
-                curr.set(std::cmp::max(0, curr() - 1));
-            }),
-            EventHandler::new(move |_| {
-                curr.set(std::cmp::min(total_p, curr() + 1));
-            }),
-            curr(),
-            total / n_pagn,
-        )
+    pub fn new(curr: Signal<u64>, per: Signal<u64>, total: u64) -> Self {
+        Self { curr, per, total }
+    }
+
+    /// At least one page, so an empty table still reads "page 1 of 1".
+    fn pages(&self) -> u64 {
+        self.total.div_ceil((self.per)().max(1)).max(1)
+    }
+
+    fn last(&self) -> u64 {
+        self.pages() - 1
+    }
+
+    fn go(&mut self, page: u64) {
+        self.curr.set(page.min(self.last()));
+    }
+
+    fn prev(&mut self) {
+        self.go((self.curr)().saturating_sub(1));
+    }
+
+    fn next(&mut self) {
+        self.go((self.curr)().saturating_add(1));
+    }
+
+    /// Keeps the first row of the current page in view when the page size changes.
+    fn resize(&mut self, per: u64) {
+        let first = (self.curr)() * (self.per)();
+        self.per.set(per);
+        self.go(first / per.max(1));
+    }
+
+    /// The 1-based rows shown on the current page.
+    fn showing(&self) -> (u64, u64) {
+        if self.total == 0 {
+            return (0, 0);
+        }
+        let first = (self.curr)().min(self.last()) * (self.per)();
+        (first + 1, (first + (self.per)()).min(self.total))
+    }
+
+    fn sizes(&self) -> Vec<u64> {
+        let mut sizes = PAGE_SIZES.to_vec();
+        if !sizes.contains(&(self.per)()) {
+            sizes.push((self.per)());
+            sizes.sort();
+        }
+        sizes
     }
 }
 
+#[component]
+fn PaginationControls(handler: PaginationHandler) -> Element {
+    let mut pagn = handler;
+    let mut jump = use_signal(String::new);
+    let curr = (pagn.curr)();
+    let (first, last) = pagn.showing();
+    let pages = pagn.pages();
+    let mut go_to_input = move || {
+        if let Ok(page) = jump().trim().parse::<u64>() {
+            pagn.go(page.saturating_sub(1));
+        }
+        jump.set(String::new());
+    };
+
+    rsx! {
+        div { class: "table-row table-header-color pagination-button",
+            span { class: "pagination-showing", "Showing {first}\u{2013}{last} of {pagn.total}" }
+            label {
+                "Rows"
+                select {
+                    "aria-label": "Rows per page",
+                    value: "{pagn.per}",
+                    onchange: move |evt| {
+                        if let Ok(per) = evt.value().parse() {
+                            pagn.resize(per);
+                        }
+                    },
+                    {pagn.sizes().into_iter().map(|it| rsx! {
+                        option { value: "{it}", "{it}" }
+                    })}
+                }
+            }
+            button {
+                "aria-label": "First page",
+                disabled: curr == 0,
+                onclick: move |_| pagn.go(0),
+                "<<"
+            }
+            button {
+                "aria-label": "Previous page",
+                disabled: curr == 0,
+                onclick: move |_| pagn.prev(),
+                "<"
+            }
+            "{curr.min(pages - 1) + 1} of {pages}"
+            button {
+                "aria-label": "Next page",
+                disabled: curr >= pagn.last(),
+                onclick: move |_| pagn.next(),
+                ">"
+            }
+            button {
+                "aria-label": "Last page",
+                disabled: curr >= pagn.last(),
+                onclick: move |_| pagn.go(pagn.last()),
+                ">>"
+            }
+            input {
+                r#type: "number",
+                "aria-label": "Go to page",
+                placeholder: "Page",
+                min: 1,
+                max: "{pages}",
+                value: jump(),
+                oninput: move |evt| jump.set(evt.value()),
+                onkeydown: move |evt| {
+                    if evt.key() == Key::Enter {
+                        go_to_input();
+                    }
+                },
+            }
+            button { "aria-label": "Go to page", onclick: move |_| go_to_input(), "Go" }
+        }
+    }
+}
+
+const EXPORT_BATCH: u64 = 100;
+
 pub trait PaginatedTableLike: TableLike + Serialize + Clone + PartialEq + 'static {
     type Inputs: Clone + PartialEq + Serialize + 'static = ();
 
     type Data: TableLike + Serialize + Clone + PartialEq + 'static = Self;
 
-    type Fut: Future<Output = PaginationResult<Self::Data>> + 'static =
-        Pin<Box<dyn Future<Output = PaginationResult<Self::Data>>>>;
+    type Fut: Future<Output = anyhow::Result<PaginationResult<Self::Data>>> + 'static =
+        Pin<Box<dyn Future<Output = anyhow::Result<PaginationResult<Self::Data>>>>>;
 
     fn n_per_paginated() -> u64;
 
-    fn query_function() -> Box<dyn Fn(u64, u64, Option<Self::Inputs>) -> Self::Fut>;
+    fn query_function(zk: ZkClient) -> Box<dyn Fn(u64, u64, Option<Self::Inputs>) -> Self::Fut>;
 
+    /// `n` is the preferred page size. Rows per page start from it and follow it when the preference changes,
+    /// a size picked in the table itself only lasts while the table is mounted and isn't persisted or put in the URL.
     fn paginated_table_handler(
-        n: u64,
+        n: Memo<u64>,
         inps: Memo<Option<Self::Inputs>>,
+        curr: Signal<u64>,
         future: Box<dyn Fn(u64, u64, Option<Self::Inputs>) -> Self::Fut>,
     ) -> Element {
-        let curr = use_signal(|| 0u64);
-        let future = std::rc::Rc::new(future);
-        let resource = use_resource(move || {
+        let tick = use_live_tick();
+        let mut per = use_signal(|| *n.peek());
+        let mut page = curr;
+        use_effect(move || {
+            let n = n();
+            if *per.peek() != n {
+                // Stay on the page holding the first row shown so far
+                let first = *page.peek() * *per.peek();
+                per.set(n);
+                page.set(first / n.max(1));
+            }
+        });
+        let future = Rc::new(future);
+        let export_future = future.clone();
+        let mut resource = use_resource(move || {
             let fut = future.clone();
-            async move { fut(curr() * n, n, inps()).await }
+            let (page, inputs) = ((curr(), per()), inps());
+            tick();
+            async move {
+                let res = fut(page.0 * page.1, page.1, inputs.clone())
+                    .await
+                    .inspect_err(|e| tracing::error!("{e}"))
+                    .map_err(|e| e.to_string());
+                (page, inputs, res)
+            }
+        });
+        // Keep showing the previous result while a live refresh or page change is loading
+        let loaded_resource = resource.read().clone().map(|(_, _, res)| res);
+
+        // Rows are only new relative to an earlier load of the same page and inputs
+        let mut seen = use_signal(|| Option::<((u64, u64), Option<Self::Inputs>, HashSet<String>)>::None);
+        let mut highlighted = use_signal(HashSet::<String>::new);
+        use_effect(move || {
+            let Some((page, inputs, Ok(res))) = resource.read().clone() else {
+                return;
+            };
+            let keys = res.data.row_keys().into_iter().collect::<HashSet<_>>();
+            highlighted.set(match seen.peek().as_ref() {
+                Some((p, i, prev)) if *p == page && *i == inputs => keys.difference(prev).cloned().collect(),
+                _ => HashSet::new(),
+            });
+            seen.set(Some((page, inputs, keys)));
         });
-        let loaded_resource = match resource.state().cloned() {
-            UseResourceState::Ready => Some(resource.value().unwrap()),
-            _ => None,
+
+        // Walks every page with the current inputs, the total can change while walking so stop on an empty page
+        let total = match resource.read().as_ref() {
+            Some((_, _, Ok(res))) => res.total,
+            _ => 0,
+        };
+        let fetch_all = FetchAllRows {
+            total,
+            fetch: Rc::new(move |mut loaded| {
+                let fut = export_future.clone();
+                let inputs = inps.peek().clone();
+                Box::pin(async move {
+                    let mut rows = vec![];
+                    while (rows.len() as u64) < EXPORT_ROW_LIMIT {
+                        let per = EXPORT_BATCH.min(EXPORT_ROW_LIMIT - rows.len() as u64);
+                        let res = fut(rows.len() as u64, per, inputs.clone()).await?;
+                        let page = res.data.rows();
+                        if page.is_empty() {
+                            break;
+                        }
+                        rows.extend(page);
+                        loaded.set(rows.len() as u64);
+                        if rows.len() as u64 >= res.total {
+                            break;
+                        }
+                    }
+                    Ok(rows)
+                })
+            }),
         };
 
-        rsx! {
-            if let Some(res) = loaded_resource {
+        match loaded_resource {
+            None => rsx! {
+                div { style: GLOBAL_PADDING,
+                    Spinner {}
+                }
+            },
+            Some(Err(message)) => rsx! {
+                div { style: GLOBAL_PADDING,
+                    ErrorCard { message, onretry: move |_| resource.restart() }
+                }
+            },
+            Some(Ok(res)) => rsx! {
                 Table {
                     data: res.data,
-                    pagination: PaginationHandler::default(res.total, curr, Some(n)),
+                    pagination: PaginationHandler::new(curr, per, res.total),
+                    highlighted: highlighted(),
+                    fetch_all,
                 }
-            }
+            },
         }
     }
 }
 
+/// Pass `page` to own the current page from outside, e.g. to keep it in the URL.
 #[component]
-pub fn PaginatedTable<T: PaginatedTableLike + PartialEq + Clone + 'static>(inputs: Memo<Option<T::Inputs>>) -> Element {
+pub fn PaginatedTable<T: PaginatedTableLike + PartialEq + Clone + 'static>(
+    inputs: Memo<Option<T::Inputs>>,
+    page: Option<Signal<u64>>,
+) -> Element {
+    let local = use_signal(|| 0u64);
+    let curr = page.unwrap_or(local);
+    let zk = use_zk();
+    let prefs = use_preferences();
+    let n = use_memo(move || prefs.read().page_size.unwrap_or(T::n_per_paginated()));
     rsx! {
-        {T::paginated_table_handler(T::n_per_paginated(), inputs, T::query_function())}
+        {T::paginated_table_handler(n, inputs, curr, T::query_function(zk))}
     }
 }
 
 #[component]
 pub fn PaginatedTableNoInputs<T: PaginatedTableLike + PartialEq + Clone + 'static>() -> Element {
     let inps = use_memo(|| Option::<T::Inputs>::None);
+    let curr = use_signal(|| 0u64);
+    let zk = use_zk();
+    let prefs = use_preferences();
+    let n = use_memo(move || prefs.read().page_size.unwrap_or(T::n_per_paginated()));
     rsx! {
-        {T::paginated_table_handler(T::n_per_paginated(), inps, T::query_function())}
+        {T::paginated_table_handler(n, inps, curr, T::query_function(zk))}
+    }
+}
+
+/// A table loaded by a query, with the loading and error states in the table's place.
+#[component]
+pub fn QueryTable<T: TableLike + PartialEq + Clone + 'static>(mut query: Query<T>) -> Element {
+    match query.state() {
+        Loadable::Ready(data) => rsx! {
+            Table { data }
+        },
+        state => rsx! {
+            div { style: GLOBAL_PADDING,
+                {state.render(move || query.retry(), |_| rsx! {})}
+            }
+        },
     }
 }
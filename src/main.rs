@@ -2,45 +2,89 @@
 #![allow(clippy::type_complexity)]
 
 use dioxus::prelude::*;
-use once_cell::sync::Lazy;
+use utils::environment::use_environments_provider;
+use utils::preferences::use_preferences_provider;
 use views::Dashboard;
 use views::ImageDetails;
 use views::Navbar;
 use views::NodeDetails;
+use views::Provers;
+use views::SearchResults;
+use views::Settings;
 use views::TaskDetails;
 use views::UserDetails;
-use zkp_service_helper::helper::ZkWasmServiceHelper;
 
 mod components;
+#[cfg(test)]
+mod testing;
 mod utils;
 mod views;
 
-pub static ZKH: Lazy<ZkWasmServiceHelper> =
-    Lazy::new(|| ZkWasmServiceHelper::new(utils::config::CONFIG.api.url.clone()));
-
 const GLOBAL_PADDING: &str = "padding: 0rem 7rem;";
 
 #[derive(Debug, Clone, Routable, PartialEq)]
 #[rustfmt::skip]
 enum Route {
     #[layout(Navbar)]
-    #[route("/")]
-    Dashboard {},
+    #[route("/?:q&:task_type&:status&:after&:before&:page")]
+    Dashboard { q: String, task_type: String, status: String, after: String, before: String, page: u64 },
     #[route("/task/:id")]
     TaskDetails { id: String },
     #[route("/image/:id")]
     ImageDetails { id: String },
     #[route("/node/:id")]
     NodeDetails { id: String },
+    #[route("/provers?:window")]
+    Provers { window: String },
     #[route("/user/:id")]
     UserDetails { id: String },
+    #[route("/search?:q")]
+    SearchResults { q: String },
+    #[route("/settings")]
+    Settings {},
+}
+
+impl Route {
+    /// The dashboard without any filters applied.
+    fn home() -> Self {
+        Route::Dashboard {
+            q: String::new(),
+            task_type: String::new(),
+            status: String::new(),
+            after: String::new(),
+            before: String::new(),
+            page: 0,
+        }
+    }
+
+    /// The URL without the query parameters left at their defaults, empty strings and the first page.
+    /// Missing parameters parse back to the same defaults.
+    fn href(&self) -> String {
+        let url = self.to_string();
+        let Some((path, query)) = url.split_once('?') else {
+            return url;
+        };
+        let query = query
+            .split('&')
+            .filter(|it| !it.ends_with('=') && !matches!(*it, "page=0" | "page=1"))
+            .collect::<Vec<_>>()
+            .join("&");
+        if query.is_empty() {
+            path.to_string()
+        } else {
+            format!("{path}?{query}")
+        }
+    }
 }
 
 #[component]
 fn App() -> Element {
     tracing::info!("ZKP Web App started!");
+    use_preferences_provider();
+    use_environments_provider();
     rsx! {
         document::Link { rel: "icon", href: asset!("/assets/favicon.ico") }
+        document::Stylesheet { href: asset!("/assets/styling/theme.css") }
         document::Stylesheet { href: asset!("/assets/styling/main.css") }
         Router::<Route> {}
     }
@@ -50,3 +94,24 @@ fn main() {
     console_error_panic_hook::set_once();
     dioxus::launch(App);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dashboard_urls_leave_out_defaults() {
+        assert_eq!(Route::home().href(), "/");
+        let route = Route::Dashboard {
+            q: "3f9a1c7e".to_string(),
+            task_type: String::new(),
+            status: "Fail".to_string(),
+            after: String::new(),
+            before: String::new(),
+            page: 3,
+        };
+        assert_eq!(route.href(), "/?q=3f9a1c7e&status=Fail&page=3");
+        assert_eq!(route.href().parse::<Route>().ok(), Some(route));
+        assert_eq!(Route::Settings {}.href(), "/settings");
+    }
+}
@@ -0,0 +1,104 @@
+//! Renders components to HTML for snapshot tests.
+//!
+//! Content is rendered inside a router of its own, links still point at the app's routes since they resolve to paths.
+//! The HTML is printed one tag or text run per line, so a changed cell only changes its own lines in a snapshot.
+//! Fixtures come from the same files as the mock backend, `minimal` has every optional field missing.
+
+use dioxus::history::MemoryHistory;
+use dioxus::prelude::*;
+use std::rc::Rc;
+use zkp_service_helper::helper::ZkWasmServiceHelper;
+
+use crate::utils::mock::Fixtures;
+use crate::utils::preferences::Preferences;
+use crate::utils::service::ZkClient;
+use crate::utils::DisplayTimeZone;
+
+pub fn fixtures() -> Fixtures {
+    serde_json::from_str(include_str!("../fixtures/backend.json")).expect("Fixtures should parse")
+}
+
+pub fn minimal() -> Fixtures {
+    serde_json::from_str(include_str!("../fixtures/minimal.json")).expect("Minimal fixtures should parse")
+}
+
+#[derive(Clone)]
+struct Content(Rc<dyn Fn() -> Element>);
+
+/// Elements without a closing tag, which don't open a level of nesting.
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track", "wbr",
+];
+
+#[derive(Debug, Clone, Routable, PartialEq)]
+enum HarnessRoute {
+    #[route("/")]
+    Harness {},
+}
+
+#[component]
+fn Root() -> Element {
+    // Never queried while rendering, download buttons only need a client to exist
+    use_context_provider(|| ZkClient::new(ZkWasmServiceHelper::new(String::new())));
+    // Timestamps are shown in UTC so snapshots don't depend on the machine's time zone
+    use_context_provider(|| {
+        let mut prefs = Preferences::default();
+        prefs.time.time_zone = DisplayTimeZone::Utc;
+        Signal::new(prefs)
+    });
+    use_context_provider(|| Rc::new(MemoryHistory::with_initial_path(HarnessRoute::Harness {})) as Rc<dyn History>);
+    rsx! {
+        Router::<HarnessRoute> {}
+    }
+}
+
+/// The only route, showing the content under test.
+#[component]
+fn Harness() -> Element {
+    let Content(content) = use_context();
+    content()
+}
+
+/// Renders `content` to indented HTML with default preferences, except for timestamps in UTC.
+pub fn render(content: impl Fn() -> Element + 'static) -> String {
+    let mut dom = VirtualDom::new(Root).with_root_context(Content(Rc::new(content)));
+    dom.rebuild_in_place();
+    pretty(&dioxus_ssr::render(&dom))
+}
+
+/// Puts every tag and text run on its own line, indented by how deeply it's nested.
+fn pretty(html: &str) -> String {
+    let mut out = String::new();
+    let mut depth = 0usize;
+    let mut rest = html;
+    while !rest.is_empty() {
+        let end = match rest.find('<') {
+            Some(0) => rest.find('>').map_or(rest.len(), |i| i + 1),
+            Some(i) => i,
+            None => rest.len(),
+        };
+        let (token, tail) = rest.split_at(end);
+        rest = tail;
+        if token.starts_with("</") {
+            depth = depth.saturating_sub(1);
+        }
+        let line = token.trim();
+        if !line.is_empty() {
+            out.push_str(&"  ".repeat(depth));
+            out.push_str(line);
+            out.push('\n');
+        }
+        let Some(tag) = token.strip_prefix('<') else {
+            continue;
+        };
+        let name = tag
+            .split(|c: char| c.is_whitespace() || c == '>' || c == '/')
+            .next()
+            .unwrap_or_default();
+        let opens = !tag.starts_with('/') && !tag.starts_with('!') && !tag.ends_with("/>");
+        if opens && !VOID_ELEMENTS.contains(&name) {
+            depth += 1;
+        }
+    }
+    out
+}
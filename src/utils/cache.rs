@@ -0,0 +1,328 @@
+use futures::future::LocalBoxFuture;
+use futures::future::Shared;
+use futures::FutureExt;
+use serde::Serialize;
+use std::any::Any;
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::future::Future;
+use std::rc::Rc;
+use web_time::Duration;
+use web_time::Instant;
+use zkp_service_helper::interface::AddImageParams;
+use zkp_service_helper::interface::AddTaskResult;
+use zkp_service_helper::interface::AppConfig;
+use zkp_service_helper::interface::AutoSubmitProof;
+use zkp_service_helper::interface::ConciseTask;
+use zkp_service_helper::interface::Image;
+use zkp_service_helper::interface::PaginationResult;
+use zkp_service_helper::interface::ProverNode;
+use zkp_service_helper::interface::ProverNodeTimeRangeStats;
+use zkp_service_helper::interface::ProverNodeTimeRangeStatsParams;
+use zkp_service_helper::interface::ProvingParams;
+use zkp_service_helper::interface::ResetImageParams;
+use zkp_service_helper::interface::Round1Info;
+use zkp_service_helper::interface::Round2Info;
+use zkp_service_helper::interface::StatisticsInfo;
+use zkp_service_helper::interface::Subscription;
+use zkp_service_helper::interface::Task;
+use zkp_service_helper::interface::TaskStatus;
+use zkp_service_helper::interface::TaskType;
+use zkp_service_helper::interface::User;
+use zkp_service_helper::interface::WithSignature;
+
+use crate::utils::service::ZkService;
+
+type Value = Rc<dyn Any>;
+type Pending = Shared<LocalBoxFuture<'static, Result<Value, Rc<String>>>>;
+
+enum Slot {
+    /// A response and when it stops being reused.
+    Ready(Instant, Value),
+    Pending(u64, Pending),
+}
+
+/// Drops responses past their TTL, so keys that are never read again don't stay for the rest of the session.
+fn prune(slots: &mut HashMap<String, Slot>, now: Instant) {
+    slots.retain(|_, slot| !matches!(slot, Slot::Ready(expires, _) if *expires <= now));
+}
+
+/// How long each endpoint's responses are reused, lists stay short so live refresh still sees new rows.
+mod ttl {
+    use web_time::Duration;
+
+    pub const CONFIG: Duration = Duration::from_secs(600);
+    pub const IMAGE: Duration = Duration::from_secs(60);
+    pub const USER: Duration = Duration::from_secs(60);
+    pub const TASK: Duration = Duration::from_secs(5);
+    pub const STATISTICS: Duration = Duration::from_secs(10);
+    pub const NODE_STATS: Duration = Duration::from_secs(30);
+    pub const LIST: Duration = Duration::from_secs(3);
+}
+
+fn key(endpoint: &str, args: &impl Serialize) -> String {
+    format!("{endpoint}:{}", serde_json::to_string(args).unwrap_or_default())
+}
+
+/// The service helper behind a response cache, reads are cached per endpoint and writes invalidate them.
+/// Each helper keeps its own cache so helpers for different services never share responses.
+pub struct CachedServiceHelper {
+    inner: Rc<dyn ZkService>,
+    slots: RefCell<HashMap<String, Slot>>,
+    next_id: Cell<u64>,
+}
+
+impl CachedServiceHelper {
+    pub fn new(inner: impl ZkService + 'static) -> Self {
+        Self {
+            inner: Rc::new(inner),
+            slots: RefCell::new(HashMap::new()),
+            next_id: Cell::new(0),
+        }
+    }
+
+    /// Returns a fresh cached value for `key`, joins a request already in flight, or runs `fetch`.
+    /// Failures are shared with every caller waiting on them but never cached.
+    async fn cached<T: Clone + 'static, Fut: Future<Output = anyhow::Result<T>> + 'static>(
+        &self,
+        key: String,
+        ttl: Duration,
+        fetch: impl FnOnce(Rc<dyn ZkService>) -> Fut,
+    ) -> anyhow::Result<T> {
+        let (id, pending) = {
+            let mut slots = self.slots.borrow_mut();
+            match slots.get(&key) {
+                Some(Slot::Ready(expires, value)) if Instant::now() < *expires => (None, Err(value.clone())),
+                Some(Slot::Pending(id, pending)) => (Some(*id), Ok(pending.clone())),
+                _ => {
+                    prune(&mut slots, Instant::now());
+                    let id = self.next_id.replace(self.next_id.get() + 1);
+                    let pending = fetch(self.inner.clone())
+                        .map(|res| res.map(|it| Rc::new(it) as Value).map_err(|e| Rc::new(e.to_string())))
+                        .boxed_local()
+                        .shared();
+                    slots.insert(key.clone(), Slot::Pending(id, pending.clone()));
+                    (Some(id), Ok(pending))
+                }
+            }
+        };
+        let res = match pending {
+            Err(value) => Ok(value),
+            Ok(pending) => {
+                let res = pending.await;
+                // Only settle the slot if it wasn't invalidated or replaced while loading
+                let mut slots = self.slots.borrow_mut();
+                if matches!(slots.get(&key), Some(Slot::Pending(it, _)) if Some(*it) == id) {
+                    match &res {
+                        Ok(value) => slots.insert(key.clone(), Slot::Ready(Instant::now() + ttl, value.clone())),
+                        Err(_) => slots.remove(&key),
+                    };
+                }
+                res
+            }
+        };
+        res.map_err(|e| anyhow::anyhow!("{e}"))?
+            .downcast_ref::<T>()
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Cached value for {key} has an unexpected type"))
+    }
+
+    /// Drops every cached response of the given endpoints so the next read refetches.
+    fn invalidate(&self, endpoints: &[&str]) {
+        self.slots
+            .borrow_mut()
+            .retain(|key, _| !endpoints.iter().any(|it| key.starts_with(&format!("{it}:"))));
+    }
+
+    pub async fn query_statistics(&self) -> anyhow::Result<StatisticsInfo> {
+        self.cached(key("query_statistics", &()), ttl::STATISTICS, move |zk| async move {
+            zk.query_statistics().await
+        })
+        .await
+    }
+
+    pub async fn query_config(&self) -> anyhow::Result<AppConfig> {
+        self.cached(key("query_config", &()), ttl::CONFIG, move |zk| async move {
+            zk.query_config().await
+        })
+        .await
+    }
+
+    pub async fn query_image(&self, md5: String) -> anyhow::Result<Option<Image>> {
+        self.cached(key("query_image", &md5), ttl::IMAGE, move |zk| async move {
+            zk.query_image(md5).await
+        })
+        .await
+    }
+
+    pub async fn query_user(&self, address: String) -> anyhow::Result<Option<User>> {
+        self.cached(key("query_user", &address), ttl::USER, move |zk| async move {
+            zk.query_user(address).await
+        })
+        .await
+    }
+
+    pub async fn query_user_subscription(&self, address: String) -> anyhow::Result<Option<Subscription>> {
+        self.cached(key("query_user_subscription", &address), ttl::USER, move |zk| async move {
+            zk.query_user_subscription(address).await
+        })
+        .await
+    }
+
+    pub async fn query_task_from_id(&self, id: String) -> anyhow::Result<Option<Task>> {
+        self.cached(key("query_task_from_id", &id), ttl::TASK, move |zk| async move {
+            zk.query_task_from_id(id).await
+        })
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn query_concise_tasks(
+        &self,
+        user_address: Option<String>,
+        md5: Option<String>,
+        id: Option<String>,
+        tasktype: Option<TaskType>,
+        status: Option<TaskStatus>,
+        start: Option<u64>,
+        total: Option<u64>,
+    ) -> anyhow::Result<PaginationResult<Vec<ConciseTask>>> {
+        let args = (&user_address, &md5, &id, &tasktype, &status, start, total);
+        self.cached(key("query_concise_tasks", &args), ttl::LIST, move |zk| async move {
+            zk.query_concise_tasks(user_address, md5, id, tasktype, status, start, total)
+                .await
+        })
+        .await
+    }
+
+    pub async fn query_node_statistics(
+        &self,
+        address: Option<String>,
+        start: Option<u64>,
+        total: Option<u64>,
+    ) -> anyhow::Result<PaginationResult<Vec<ProverNode>>> {
+        self.cached(
+            key("query_node_statistics", &(&address, start, total)),
+            ttl::LIST,
+            move |zk| async move { zk.query_node_statistics(address, start, total).await },
+        )
+        .await
+    }
+
+    pub async fn query_prover_node_timerange_stats(
+        &self,
+        params: ProverNodeTimeRangeStatsParams,
+    ) -> anyhow::Result<Vec<ProverNodeTimeRangeStats>> {
+        self.cached(
+            key("query_prover_node_timerange_stats", &params),
+            ttl::NODE_STATS,
+            move |zk| async move { zk.query_prover_node_timerange_stats(params).await },
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn query_auto_submit_proofs(
+        &self,
+        a: Option<String>,
+        b: Option<String>,
+        c: Option<String>,
+        d: Option<String>,
+        e: Option<u64>,
+        start: Option<u64>,
+        total: Option<u64>,
+    ) -> anyhow::Result<PaginationResult<Vec<AutoSubmitProof>>> {
+        self.cached(
+            key("query_auto_submit_proofs", &(&a, &b, &c, &d, e, start, total)),
+            ttl::LIST,
+            move |zk| async move { zk.query_auto_submit_proofs(a, b, c, d, e, start, total).await },
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn query_round1_info(
+        &self,
+        a: Option<String>,
+        b: Option<String>,
+        c: Option<String>,
+        d: Option<String>,
+        e: Option<String>,
+        f: Option<u64>,
+        start: Option<u64>,
+        total: Option<u64>,
+    ) -> anyhow::Result<PaginationResult<Vec<Round1Info>>> {
+        self.cached(
+            key("query_round1_info", &(&a, &b, &c, &d, &e, f, start, total)),
+            ttl::LIST,
+            move |zk| async move { zk.query_round1_info(a, b, c, d, e, f, start, total).await },
+        )
+        .await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn query_round2_info(
+        &self,
+        a: Option<String>,
+        b: Option<String>,
+        c: Option<String>,
+        d: Option<String>,
+        e: Option<u64>,
+        start: Option<u64>,
+        total: Option<u64>,
+    ) -> anyhow::Result<PaginationResult<Vec<Round2Info>>> {
+        self.cached(
+            key("query_round2_info", &(&a, &b, &c, &d, e, start, total)),
+            ttl::LIST,
+            move |zk| async move { zk.query_round2_info(a, b, c, d, e, start, total).await },
+        )
+        .await
+    }
+
+    /// Not cached, the table can be large and is only fetched on demand.
+    pub async fn query_task_external_host_table(&self, id: String) -> anyhow::Result<Vec<u8>> {
+        self.inner.query_task_external_host_table(id).await
+    }
+
+    pub async fn add_new_wasm_image(&self, params: WithSignature<AddImageParams>) -> anyhow::Result<AddTaskResult> {
+        let res = self.inner.add_new_wasm_image(params).await?;
+        self.invalidate(&["query_image", "query_concise_tasks", "query_statistics"]);
+        Ok(res)
+    }
+
+    pub async fn add_proving_task(&self, params: WithSignature<ProvingParams>) -> anyhow::Result<AddTaskResult> {
+        let res = self.inner.add_proving_task(params).await?;
+        self.invalidate(&["query_concise_tasks", "query_statistics", "query_user"]);
+        Ok(res)
+    }
+
+    pub async fn add_reset_task(&self, params: WithSignature<ResetImageParams>) -> anyhow::Result<AddTaskResult> {
+        let res = self.inner.add_reset_task(params).await?;
+        self.invalidate(&["query_image", "query_concise_tasks", "query_statistics"]);
+        Ok(res)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pruning_drops_only_expired_responses() {
+        let now = Instant::now();
+        let value = || Rc::new(()) as Value;
+        let mut slots = HashMap::from([
+            ("expired".to_string(), Slot::Ready(now, value())),
+            ("fresh".to_string(), Slot::Ready(now + Duration::from_secs(1), value())),
+            (
+                "pending".to_string(),
+                Slot::Pending(0, futures::future::pending().boxed_local().shared()),
+            ),
+        ]);
+        prune(&mut slots, now);
+        let mut keys = slots.keys().map(String::as_str).collect::<Vec<_>>();
+        keys.sort();
+        assert_eq!(keys, ["fresh", "pending"]);
+    }
+}
@@ -1,19 +1,108 @@
+//! Service environments, loaded at startup from a `config.toml` or `config.json` served next to the app.
+//! The `config.toml` baked into the binary is used when neither is served or they fail to parse.
+
 const CONFIG_TOML: &str = include_str!("../../../config.toml");
 
-pub static CONFIG: once_cell::sync::Lazy<Config> =
-    once_cell::sync::Lazy::new(|| load_config().expect("Should load config"));
+/// Where the served config is looked for, relative to the page origin, in order.
+const SERVED_CONFIGS: [&str; 2] = ["/config.toml", "/config.json"];
 
-#[derive(serde::Deserialize)]
-pub struct ApiConfig {
+#[derive(Clone, PartialEq, serde::Deserialize)]
+pub struct Environment {
+    pub name: String,
     pub url: String,
 }
 
-#[derive(serde::Deserialize)]
+#[derive(Clone, PartialEq, serde::Deserialize)]
 pub struct Config {
-    pub api: ApiConfig,
+    /// Name of the environment used when none has been picked, the first one if missing.
+    pub default: Option<String>,
+    pub environments: Vec<Environment>,
+}
+
+impl Config {
+    pub fn baked() -> Self {
+        parse("config.toml", CONFIG_TOML).expect("Baked config.toml should parse")
+    }
+
+    pub fn environment(&self, name: &str) -> Option<&Environment> {
+        self.environments.iter().find(|it| it.name == name)
+    }
+
+    /// The named environment, falling back to the default one when `name` isn't configured.
+    pub fn resolve(&self, name: Option<&str>) -> &Environment {
+        name.and_then(|it| self.environment(it))
+            .or_else(|| self.default.as_deref().and_then(|it| self.environment(it)))
+            .or_else(|| self.environments.first())
+            .expect("Config should have an environment")
+    }
 }
 
-pub fn load_config() -> anyhow::Result<Config> {
-    let config = toml::from_str::<Config>(CONFIG_TOML)?;
+fn parse(path: &str, raw: &str) -> anyhow::Result<Config> {
+    let config = if path.ends_with(".json") {
+        serde_json::from_str::<Config>(raw)?
+    } else {
+        toml::from_str::<Config>(raw)?
+    };
+    if config.environments.is_empty() {
+        anyhow::bail!("{path} has no environments");
+    }
     Ok(config)
 }
+
+async fn fetch(path: &str) -> anyhow::Result<Config> {
+    let origin = web_sys::window()
+        .and_then(|it| it.location().origin().ok())
+        .ok_or_else(|| anyhow::anyhow!("No page origin to load {path} from"))?;
+    let raw = reqwest::get(format!("{origin}{path}"))
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    parse(path, &raw)
+}
+
+/// The first served config that loads, or the baked-in one.
+pub async fn load_config() -> Config {
+    for path in SERVED_CONFIGS {
+        match fetch(path).await {
+            Ok(config) => return config,
+            Err(e) => tracing::info!("Not using served {path}: {e}"),
+        }
+    }
+    Config::baked()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn baked_config_has_default_environment() {
+        let config = Config::baked();
+        let default = config.default.as_deref().expect("Baked config should name a default");
+        assert!(config.environment(default).is_some());
+    }
+
+    #[test]
+    fn parses_json_config() {
+        let config = parse(
+            "/config.json",
+            r#"{"environments": [{"name": "testnet", "url": "http://localhost:8109"}]}"#,
+        )
+        .unwrap();
+        assert!(config.resolve(None).name == "testnet");
+    }
+
+    #[test]
+    fn rejects_config_without_environments() {
+        assert!(parse("/config.toml", "environments = []").is_err());
+        assert!(parse("/config.toml", "[api]\nurl = \"http://localhost:8108\"").is_err());
+    }
+
+    #[test]
+    fn unknown_environment_resolves_to_default() {
+        let config = Config::baked();
+        assert!(config.resolve(Some("nowhere")).name == "mainnet");
+        assert!(config.resolve(Some("local")).name == "local");
+    }
+}
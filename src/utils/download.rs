@@ -0,0 +1,40 @@
+use gloo_timers::callback::Timeout;
+use wasm_bindgen::JsCast;
+use wasm_bindgen::JsValue;
+use wasm_bindgen_futures::js_sys;
+use web_sys::window;
+use web_sys::Blob;
+use web_sys::BlobPropertyBag;
+use web_sys::HtmlAnchorElement;
+use web_sys::Url;
+
+/// How long the object URL outlives the click, browsers start the download asynchronously.
+const REVOKE_DELAY_MS: u32 = 1000;
+
+/// Saves bytes as a file by clicking a temporary anchor pointing at an object URL.
+/// The anchor is attached to the page while it's clicked, Firefox and some Safari versions ignore detached ones.
+pub fn download_bytes(filename: &str, bytes: &[u8], mime: &str) -> Result<(), JsValue> {
+    let document = window().ok_or("no window")?.document().ok_or("no document")?;
+
+    let parts = js_sys::Array::of1(&js_sys::Uint8Array::from(bytes));
+    let options = BlobPropertyBag::new();
+    options.set_type(mime);
+    let blob = Blob::new_with_u8_array_sequence_and_options(&parts, &options)?;
+    let url = Url::create_object_url_with_blob(&blob)?;
+
+    let anchor = document.create_element("a")?.dyn_into::<HtmlAnchorElement>()?;
+    anchor.set_href(&url);
+    anchor.set_download(filename);
+    let body = document.body().ok_or("no body")?;
+    body.append_child(&anchor)?;
+    anchor.click();
+    anchor.remove();
+
+    Timeout::new(REVOKE_DELAY_MS, move || {
+        if let Err(e) = Url::revoke_object_url(&url) {
+            tracing::error!("Failed to revoke download URL: {e:?}");
+        }
+    })
+    .forget();
+    Ok(())
+}
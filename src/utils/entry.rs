@@ -1,6 +1,11 @@
 use crate::components::card::EntryLike;
 use crate::utils::bytes_to_bigint;
+use crate::utils::download::download_bytes;
 use crate::utils::enum_to_string;
+use crate::utils::format_number;
+use crate::utils::preferences::preferences;
+use crate::utils::service::use_zk;
+use crate::utils::service::ZkService;
 use crate::utils::shorten_address;
 use crate::utils::shorten_md5;
 use crate::utils::task_status_to_background_color;
@@ -30,6 +35,8 @@ pub enum AddressStyle {
 #[derive(Clone, PartialEq, Default)]
 pub enum ZkEntry {
     Raw(String),
+    /// An integer shown in the preferred number format.
+    Number(String),
     #[default]
     Empty,
     LongInput(Vec<String>),
@@ -44,6 +51,97 @@ pub enum ZkEntry {
     TaskStatus(TaskStatus),
 }
 
+/// What a table column sorts by, keys of different kinds order numbers first, then times, then text.
+#[derive(Clone, Debug)]
+pub enum SortKey {
+    Number(f64),
+    Time(i64),
+    Text(String),
+}
+
+impl SortKey {
+    fn rank(&self) -> u8 {
+        match self {
+            Self::Number(_) => 0,
+            Self::Time(_) => 1,
+            Self::Text(_) => 2,
+        }
+    }
+}
+
+impl Ord for SortKey {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match (self, other) {
+            (Self::Number(a), Self::Number(b)) => a.total_cmp(b),
+            (Self::Time(a), Self::Time(b)) => a.cmp(b),
+            (Self::Text(a), Self::Text(b)) => a.cmp(b),
+            _ => self.rank().cmp(&other.rank()),
+        }
+    }
+}
+
+impl PartialOrd for SortKey {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for SortKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for SortKey {}
+
+impl ZkEntry {
+    /// `None` for entries that have no meaningful order, such as byte dumps, logs and missing values.
+    pub fn sort_key(&self) -> Option<SortKey> {
+        match self {
+            Self::Raw(cell) if cell == "N/A" => None,
+            Self::Raw(cell) => Some(
+                cell.parse::<f64>()
+                    .map(SortKey::Number)
+                    .unwrap_or_else(|_| SortKey::Text(cell.clone())),
+            ),
+            Self::Number(cell) => cell.parse::<f64>().ok().map(SortKey::Number),
+            Self::Address(addr, _, _) | Self::MaybeAddress(Some(addr), _, _) => Some(SortKey::Text(addr.clone())),
+            Self::Timestamp(Some(ts), _) => ts
+                .parse::<chrono::DateTime<chrono::Utc>>()
+                .ok()
+                .map(|it| SortKey::Time(it.timestamp_millis())),
+            Self::TaskType(cell) => enum_to_string(cell).ok().map(SortKey::Text),
+            Self::TaskStatus(cell) => enum_to_string(cell).ok().map(SortKey::Text),
+            _ => None,
+        }
+    }
+
+    /// The full value for exports, addresses are never shortened and timestamps stay in RFC 3339.
+    pub fn export_value(&self) -> String {
+        match self {
+            Self::Raw(cell) | Self::Number(cell) => cell.clone(),
+            Self::Empty => String::new(),
+            Self::LongInput(cells) => cells.join(" "),
+            Self::Logs(cell) => cell.clone().unwrap_or_default(),
+            Self::Address(addr, _, _) => addr.clone(),
+            Self::MaybeAddress(addr, _, _) => addr.clone().unwrap_or_default(),
+            Self::Timestamp(ts, _) => ts.clone().unwrap_or_default(),
+            Self::DownloadButton(id) => id.clone(),
+            Self::Bytes(cells, chunksize) => bytes_to_bigint(cells, *chunksize)
+                .into_iter()
+                .map(|cell| format!("{cell:#X}"))
+                .collect::<Vec<_>>()
+                .join(" "),
+            Self::Checksum(cell) => cell
+                .as_ref()
+                .map(|it| format!("0x{} 0x{}", hex::encode(&it.x), hex::encode(&it.y)))
+                .unwrap_or_default(),
+            Self::TaskType(cell) => enum_to_string(cell).unwrap_or_default(),
+            Self::TaskStatus(cell) => enum_to_string(cell).unwrap_or_default(),
+        }
+    }
+}
+
 impl EntryLike for ZkEntry {
     fn into_cell(self) -> Element {
         match self {
@@ -52,6 +150,7 @@ impl EntryLike for ZkEntry {
                     div { "{cell}" }
                 }
             }
+            Self::Number(cell) => Self::Raw(format_number(&cell, preferences().number_format)).into_cell(),
             Self::Empty => Self::Raw("N/A".to_string()).into_cell(),
             Self::LongInput(cells) => {
                 rsx! {
@@ -69,6 +168,19 @@ impl EntryLike for ZkEntry {
                 .unwrap_or_default()
                 .into_cell(),
             Self::Address(addr, style, kind) => {
+                let label = match kind {
+                    AddressKind::User => match style {
+                        AddressStyle::Dashboard => shorten_address(&addr),
+                        AddressStyle::Detailed => Ok(addr.clone()),
+                    },
+                    AddressKind::PrefixedImage => shorten_md5(&addr).map(|it| format!("MD5 {it}")),
+                    AddressKind::PrefixedTask => Ok(format!("Task ID {addr}")),
+                    _ => Ok(addr.clone()),
+                };
+                let label = match label {
+                    Ok(label) => label,
+                    Err(e) => return invalid_cell(&addr, e),
+                };
                 rsx! {
                     div {
                         class: match style {
@@ -79,38 +191,26 @@ impl EntryLike for ZkEntry {
                             to: match kind {
                                 AddressKind::User => {
                                     crate::Route::UserDetails {
-                                        id: addr.clone(),
+                                        id: addr,
                                     }
                                 }
                                 AddressKind::Image | AddressKind::PrefixedImage => {
                                     crate::Route::ImageDetails {
-                                        id: addr.clone(),
+                                        id: addr,
                                     }
                                 }
                                 AddressKind::Node => {
                                     crate::Route::NodeDetails {
-                                        id: addr.clone(),
+                                        id: addr,
                                     }
                                 }
                                 AddressKind::Task | AddressKind::PrefixedTask => {
                                     crate::Route::TaskDetails {
-                                        id: addr.clone(),
+                                        id: addr,
                                     }
                                 }
                             },
-                            {
-                                match kind {
-                                    AddressKind::User => {
-                                        match style {
-                                            AddressStyle::Dashboard => shorten_address(&addr),
-                                            AddressStyle::Detailed => addr,
-                                        }
-                                    }
-                                    AddressKind::PrefixedImage => format!("MD5 {}", shorten_md5(addr)),
-                                    AddressKind::PrefixedTask => format!("Task ID {addr}"),
-                                    _ => addr,
-                                }
-                            }
+                            "{label}"
                         }
                     }
                 }
@@ -119,18 +219,16 @@ impl EntryLike for ZkEntry {
                 .map(|cell| Self::Address(cell, style, kind))
                 .unwrap_or_default()
                 .into_cell(),
-            Self::Timestamp(ts, sty) => ts
-                .map(|cell| {
-                    rsx! {
-                        div { {timestamp_formatted(&cell, sty)} }
-                    }
-                })
-                .unwrap_or(Self::default().into_cell()),
-            Self::DownloadButton(_cell) => {
+            Self::Timestamp(None, _) => Self::default().into_cell(),
+            Self::Timestamp(Some(cell), sty) => match timestamp_formatted(&cell, sty, &preferences().time) {
+                Ok(ts) => rsx! {
+                    div { title: "{ts.full}", "{ts.text}" }
+                },
+                Err(e) => invalid_cell(&cell, e),
+            },
+            Self::DownloadButton(cell) => {
                 rsx! {
-                    div { class: "user-link-pill-box",
-                        a { "Download External Host Table Data" }
-                    }
+                    ExternalHostTableDownload { task_id: cell }
                 }
             }
             Self::Bytes(cells, chunksize) => {
@@ -164,16 +262,188 @@ impl EntryLike for ZkEntry {
                     }
                 }
             }
-            Self::TaskStatus(cell) => {
-                rsx! {
+            Self::TaskStatus(cell) => match enum_to_string(&cell) {
+                Ok(status) => rsx! {
                     div {
                         class: "status-rounded-box",
                         background_color: task_status_to_background_color(cell),
-                        {enum_to_string(&cell)}
+                        "{status}"
                     }
+                },
+                Err(e) => invalid_cell(&serde_json::to_string(&cell).unwrap_or_default(), e),
+            },
+            Self::TaskType(cell) => match enum_to_string(&cell) {
+                Ok(it) => Self::Raw(it).into_cell(),
+                Err(e) => invalid_cell(&serde_json::to_string(&cell).unwrap_or_default(), e),
+            },
+        }
+    }
+}
+
+/// Shown in place of a value that couldn't be formatted, with the raw value in a tooltip.
+fn invalid_cell(raw: &str, e: anyhow::Error) -> Element {
+    tracing::error!("{e}");
+    rsx! {
+        div { class: "invalid-value", title: "{raw}", "invalid value" }
+    }
+}
+
+#[derive(Clone, PartialEq)]
+enum DownloadState {
+    Idle,
+    Loading,
+    Failed(String),
+}
+
+async fn download_external_host_table(zk: &dyn ZkService, task_id: String) -> anyhow::Result<()> {
+    let table = zk.query_task_external_host_table(task_id.clone()).await?;
+    download_bytes(&format!("{task_id}.json"), &table, "application/json")
+        .map_err(|e| anyhow::anyhow!("Failed to save file: {e:?}"))
+}
+
+#[component]
+fn ExternalHostTableDownload(task_id: String) -> Element {
+    let mut state = use_signal(|| DownloadState::Idle);
+    let zk = use_zk();
+    let onclick = move |_| {
+        let id = task_id.clone();
+        async move {
+            if state() == DownloadState::Loading {
+                return;
+            }
+            state.set(DownloadState::Loading);
+            state.set(match download_external_host_table(&*zk.peek(), id).await {
+                Ok(()) => DownloadState::Idle,
+                Err(e) => {
+                    tracing::error!("{e}");
+                    DownloadState::Failed(e.to_string())
+                }
+            });
+        }
+    };
+
+    rsx! {
+        div { class: "user-link-pill-box",
+            a { role: "button", style: "cursor: pointer;", onclick,
+                match state() {
+                    DownloadState::Idle => "Download External Host Table Data".to_string(),
+                    DownloadState::Loading => "Downloading...".to_string(),
+                    DownloadState::Failed(_) => "Download failed, click to retry".to_string(),
+                }
+            }
+        }
+        if let DownloadState::Failed(e) = state() {
+            div { class: "download-error", "{e}" }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::fixtures;
+    use crate::testing::render;
+
+    fn render_entries(entries: Vec<ZkEntry>) -> String {
+        render(move || {
+            rsx! {
+                for entry in entries.clone() {
+                    div { {entry.into_cell()} }
                 }
             }
-            Self::TaskType(cell) => Self::Raw(enum_to_string(&cell)).into_cell(),
+        })
+    }
+
+    #[test]
+    fn text_entries() {
+        insta::assert_snapshot!(render_entries(vec![
+            ZkEntry::Raw("42".to_string()),
+            ZkEntry::Empty,
+            ZkEntry::LongInput(vec!["0x1:i64".to_string(), "0x2a:i64".to_string()]),
+            ZkEntry::LongInput(vec![]),
+            ZkEntry::Logs(Some("guest panicked: assertion failed".to_string())),
+            ZkEntry::Logs(None),
+        ]));
+    }
+
+    #[test]
+    fn address_entries() {
+        let addr = "0x7f3a9c2e41b8d5f06a1e93c4b27d8e5f9a0c1b2d".to_string();
+        let md5 = "3f9a1c7e5b2d8a4f6c0e9b1d7a3f5c8e".to_string();
+        let task = "66f1a2b3c4d5e6f7a8b9c000".to_string();
+        let mut entries = vec![];
+        for style in [AddressStyle::Dashboard, AddressStyle::Detailed] {
+            entries.extend([
+                ZkEntry::Address(addr.clone(), style.clone(), AddressKind::User),
+                ZkEntry::Address(addr.clone(), style.clone(), AddressKind::Node),
+                ZkEntry::Address(md5.clone(), style.clone(), AddressKind::Image),
+                ZkEntry::Address(md5.clone(), style.clone(), AddressKind::PrefixedImage),
+                ZkEntry::Address(task.clone(), style.clone(), AddressKind::Task),
+                ZkEntry::Address(task.clone(), style.clone(), AddressKind::PrefixedTask),
+                ZkEntry::MaybeAddress(Some(task.clone()), style.clone(), AddressKind::Task),
+                ZkEntry::MaybeAddress(None, style, AddressKind::Task),
+            ]);
         }
+        insta::assert_snapshot!(render_entries(entries));
+    }
+
+    #[test]
+    fn timestamp_entries() {
+        let ts = Some("2024-09-23T14:45:09Z".to_string());
+        insta::assert_snapshot!(render_entries(vec![
+            ZkEntry::Timestamp(ts.clone(), TimestampStyle::Simple),
+            ZkEntry::Timestamp(ts, TimestampStyle::Full),
+            ZkEntry::Timestamp(None, TimestampStyle::Full),
+        ]));
+    }
+
+    #[test]
+    fn data_entries() {
+        let checksum = fixtures().images.into_iter().find_map(|it| it.checksum);
+        assert!(checksum.is_some(), "fixtures should have an image with a checksum");
+        insta::assert_snapshot!(render_entries(vec![
+            ZkEntry::DownloadButton("66f1a2b3c4d5e6f7a8b9c000".to_string()),
+            ZkEntry::Bytes((0..64).collect(), None),
+            ZkEntry::Bytes((0..16).collect(), Some(8)),
+            ZkEntry::Bytes(vec![], None),
+            ZkEntry::Checksum(checksum),
+            ZkEntry::Checksum(None),
+        ]));
+    }
+
+    #[test]
+    fn malformed_entries() {
+        insta::assert_snapshot!(render_entries(vec![
+            ZkEntry::Address("".to_string(), AddressStyle::Dashboard, AddressKind::User),
+            ZkEntry::Address("0x7f3a".to_string(), AddressStyle::Dashboard, AddressKind::User),
+            ZkEntry::Address("0x7f3a".to_string(), AddressStyle::Detailed, AddressKind::User),
+            ZkEntry::Address("3f9a1c7e".to_string(), AddressStyle::Detailed, AddressKind::PrefixedImage),
+            ZkEntry::Address(
+                "ééééééééééééééé".to_string(),
+                AddressStyle::Detailed,
+                AddressKind::PrefixedImage
+            ),
+            ZkEntry::MaybeAddress(Some("0x7f3a".to_string()), AddressStyle::Dashboard, AddressKind::User),
+            ZkEntry::Timestamp(Some("".to_string()), TimestampStyle::Simple),
+            ZkEntry::Timestamp(Some("yesterday".to_string()), TimestampStyle::Full),
+            ZkEntry::Timestamp(Some("2024-13-45T99:99:99Z".to_string()), TimestampStyle::Full),
+        ]));
+    }
+
+    #[test]
+    fn enum_entries() {
+        insta::assert_snapshot!(render_entries(vec![
+            ZkEntry::TaskType(TaskType::Setup),
+            ZkEntry::TaskType(TaskType::Prove),
+            ZkEntry::TaskType(TaskType::Reset),
+            ZkEntry::TaskStatus(TaskStatus::Pending),
+            ZkEntry::TaskStatus(TaskStatus::Processing),
+            ZkEntry::TaskStatus(TaskStatus::DryRunSuccess),
+            ZkEntry::TaskStatus(TaskStatus::DryRunFailed),
+            ZkEntry::TaskStatus(TaskStatus::Done),
+            ZkEntry::TaskStatus(TaskStatus::Fail),
+            ZkEntry::TaskStatus(TaskStatus::Unprovable),
+            ZkEntry::TaskStatus(TaskStatus::Stale),
+        ]));
     }
 }
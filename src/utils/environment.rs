@@ -0,0 +1,61 @@
+use dioxus::prelude::*;
+
+use crate::utils::cache::CachedServiceHelper;
+use crate::utils::config::load_config;
+use crate::utils::config::Config;
+use crate::utils::config::Environment;
+use crate::utils::preferences::use_preferences;
+use crate::utils::preferences::Preferences;
+use crate::utils::service::ZkClient;
+
+fn connect(environment: &Environment) -> CachedServiceHelper {
+    tracing::info!("Using {} environment at {}", environment.name, environment.url);
+    CachedServiceHelper::new(crate::utils::mock::backend(&environment.url))
+}
+
+/// The configured environments and the one the service client talks to.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Environments {
+    pub config: Signal<Config>,
+    prefs: Signal<Preferences>,
+}
+
+impl Environments {
+    pub fn current(&self) -> Environment {
+        self.config.read().resolve(self.prefs.read().environment.as_deref()).clone()
+    }
+
+    pub fn select(&mut self, name: String) {
+        self.prefs.write().environment = Some(name);
+    }
+}
+
+pub fn use_environments() -> Environments {
+    use_context::<Environments>()
+}
+
+/// Provides the environments and a service client for the one selected in the preferences, starting with the baked-in config
+/// until the served one loads. The client is rebuilt, with an empty cache, whenever the environment changes.
+pub fn use_environments_provider() -> Environments {
+    let prefs = use_preferences();
+    let mut envs = use_context_provider(|| Environments { config: Signal::new(Config::baked()), prefs });
+    let zk = use_context_provider(|| ZkClient::new(connect(&envs.current())));
+    let mut active = use_signal(|| envs.current());
+
+    use_future(move || async move {
+        let config = load_config().await;
+        if config != *envs.config.peek() {
+            envs.config.set(config);
+        }
+    });
+
+    use_effect(move || {
+        let current = envs.current();
+        if current != *active.peek() {
+            zk.replace(connect(&current));
+            active.set(current);
+        }
+    });
+
+    envs
+}
@@ -0,0 +1,74 @@
+use crate::utils::download::download_bytes;
+use crate::utils::ZkEntry;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum TableFormat {
+    Csv,
+    Json,
+}
+
+impl TableFormat {
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Csv => "CSV",
+            Self::Json => "JSON",
+        }
+    }
+
+    fn extension(&self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Json => "json",
+        }
+    }
+
+    fn mime(&self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv",
+            Self::Json => "application/json",
+        }
+    }
+
+    fn render(&self, headers: &[&str], rows: &[Vec<ZkEntry>]) -> anyhow::Result<String> {
+        match self {
+            Self::Csv => Ok(to_csv(headers, rows)),
+            Self::Json => Ok(serde_json::to_string_pretty(&to_json(headers, rows))?),
+        }
+    }
+
+    /// Saves the rows as `{title}_{suffix}` with this format's extension.
+    pub fn export(&self, title: &str, suffix: &str, headers: &[&str], rows: &[Vec<ZkEntry>]) -> anyhow::Result<()> {
+        let name = format!("{}_{suffix}.{}", title.to_lowercase().replace(' ', "_"), self.extension());
+        download_bytes(&name, self.render(headers, rows)?.as_bytes(), self.mime())
+            .map_err(|e| anyhow::anyhow!("Failed to save {name}: {e:?}"))
+    }
+}
+
+fn csv_field(it: &str) -> String {
+    if it.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", it.replace('"', "\"\""))
+    } else {
+        it.to_string()
+    }
+}
+
+fn to_csv(headers: &[&str], rows: &[Vec<ZkEntry>]) -> String {
+    let line = |cells: Vec<String>| cells.iter().map(|it| csv_field(it)).collect::<Vec<_>>().join(",");
+    std::iter::once(line(headers.iter().map(|it| it.to_string()).collect()))
+        .chain(rows.iter().map(|row| line(row.iter().map(ZkEntry::export_value).collect())))
+        .map(|it| it + "\r\n")
+        .collect()
+}
+
+/// One object per row keyed by column header.
+fn to_json(headers: &[&str], rows: &[Vec<ZkEntry>]) -> serde_json::Value {
+    rows.iter()
+        .map(|row| {
+            headers
+                .iter()
+                .zip(row)
+                .map(|(header, entry)| (header.to_string(), serde_json::Value::String(entry.export_value())))
+                .collect::<serde_json::Map<_, _>>()
+        })
+        .collect()
+}
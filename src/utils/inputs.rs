@@ -0,0 +1,131 @@
+/// Validates a single zkWasm input in the `value:type` syntax accepted by the prover,
+/// `i64` takes a decimal or 0x prefixed hex value, `bytes` and `bytes-packed` take 0x prefixed hex bytes.
+pub fn validate_input(input: &str) -> anyhow::Result<()> {
+    let (value, kind) = input
+        .rsplit_once(':')
+        .ok_or_else(|| anyhow::anyhow!("Expected `value:type`, got `{input}`"))?;
+    match kind {
+        "i64" => {
+            let valid = match value.strip_prefix("0x") {
+                Some(hex) => u64::from_str_radix(hex, 16).is_ok(),
+                None => value.parse::<i64>().is_ok() || value.parse::<u64>().is_ok(),
+            };
+            anyhow::ensure!(valid, "`{value}` is not a valid 64 bit integer");
+        }
+        "bytes" | "bytes-packed" => {
+            let hex = value
+                .strip_prefix("0x")
+                .ok_or_else(|| anyhow::anyhow!("`{value}` must be 0x prefixed hex"))?;
+            anyhow::ensure!(hex.len() % 2 == 0, "`{value}` must have an even number of hex digits");
+            hex::decode(hex).map_err(|e| anyhow::anyhow!("`{value}` is not valid hex: {e}"))?;
+        }
+        _ => anyhow::bail!("Unknown input type `{kind}`, expected i64, bytes or bytes-packed"),
+    }
+    Ok(())
+}
+
+/// Splits a text block into one input per line, skipping blank lines.
+/// Returns every invalid line with its line number so the form can report them all at once.
+pub fn parse_inputs(text: &str) -> Result<Vec<String>, Vec<String>> {
+    let mut inputs = vec![];
+    let mut errors = vec![];
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        match validate_input(line) {
+            Ok(()) => inputs.push(line.to_string()),
+            Err(e) => errors.push(format!("Line {}: {e}", i + 1)),
+        }
+    }
+    if errors.is_empty() {
+        Ok(inputs)
+    } else {
+        Err(errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_valid_inputs() {
+        for input in [
+            "0:i64",
+            "42:i64",
+            "-42:i64",
+            "9223372036854775807:i64",
+            "-9223372036854775808:i64",
+            "18446744073709551615:i64",
+            "0x2a:i64",
+            "0xffffffffffffffff:i64",
+            "0x:bytes",
+            "0x00ff:bytes",
+            "0xDEADbeef:bytes-packed",
+        ] {
+            assert!(validate_input(input).is_ok(), "{input}");
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_integers() {
+        for input in [
+            ":i64",
+            "0x:i64",
+            "0xg1:i64",
+            "0x10000000000000000:i64",
+            "18446744073709551616:i64",
+            "-9223372036854775809:i64",
+            "4.2:i64",
+            "forty:i64",
+        ] {
+            assert!(validate_input(input).is_err(), "{input}");
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_bytes() {
+        for input in [
+            "0x123:bytes",
+            "0xzz:bytes",
+            "00ff:bytes",
+            "0x0:bytes-packed",
+            "ff:bytes-packed",
+        ] {
+            assert!(validate_input(input).is_err(), "{input}");
+        }
+        let err = validate_input("00ff:bytes").unwrap_err().to_string();
+        assert!(err.contains("0x prefixed"), "{err}");
+        let err = validate_input("0x123:bytes").unwrap_err().to_string();
+        assert!(err.contains("even number"), "{err}");
+    }
+
+    #[test]
+    fn rejects_unknown_types_and_missing_separators() {
+        let err = validate_input("42:u64").unwrap_err().to_string();
+        assert!(err.contains("Unknown input type `u64`"), "{err}");
+        for input in ["42", "0x00ff", "", "42:"] {
+            assert!(validate_input(input).is_err(), "{input}");
+        }
+        let err = validate_input("42").unwrap_err().to_string();
+        assert!(err.contains("Expected `value:type`"), "{err}");
+    }
+
+    #[test]
+    fn parse_inputs_skips_blank_lines() {
+        let inputs = parse_inputs("\n1:i64\n\n   \n  0x00:bytes  \n").unwrap();
+        assert_eq!(inputs, ["1:i64", "0x00:bytes"]);
+        assert_eq!(parse_inputs("").unwrap(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn parse_inputs_reports_every_invalid_line() {
+        let errors = parse_inputs("1:i64\n\nnope\n0x1:bytes\n\n2:i64\n42:u64").unwrap_err();
+        assert_eq!(errors.len(), 3);
+        assert!(errors[0].starts_with("Line 3: "), "{}", errors[0]);
+        assert!(errors[1].starts_with("Line 4: "), "{}", errors[1]);
+        assert!(errors[2].starts_with("Line 7: "), "{}", errors[2]);
+    }
+}
@@ -0,0 +1,57 @@
+use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
+
+pub const LIVE_INTERVALS_SECS: [u32; 4] = [5, 15, 30, 60];
+
+/// Live refresh interval in seconds shared by every query below the provider, `None` disables polling.
+#[derive(Clone, Copy, PartialEq)]
+pub struct LiveInterval(pub Signal<Option<u32>>);
+
+fn tab_hidden() -> bool {
+    web_sys::window()
+        .and_then(|it| it.document())
+        .map(|it| it.hidden())
+        .unwrap_or(false)
+}
+
+/// Waits for the interval to pass, then keeps waiting while the tab is hidden.
+async fn next_poll(interval_secs: u32) {
+    loop {
+        TimeoutFuture::new(interval_secs * 1000).await;
+        if !tab_hidden() {
+            return;
+        }
+    }
+}
+
+/// A counter bumped on every live refresh, read it inside a resource to re-run it.
+pub fn use_live_tick() -> Signal<u64> {
+    let interval = try_use_context::<LiveInterval>();
+    let mut tick = use_signal(|| 0u64);
+    let _ = use_resource(move || async move {
+        let Some(secs) = interval.and_then(|it| (it.0)()) else {
+            return;
+        };
+        loop {
+            next_poll(secs).await;
+            tick += 1;
+        }
+    });
+    tick
+}
+
+#[component]
+pub fn LiveToggle() -> Element {
+    let mut interval = use_context::<LiveInterval>().0;
+    rsx! {
+        select {
+            class: "live-toggle",
+            value: interval().map(|it| it.to_string()).unwrap_or_default(),
+            onchange: move |evt| interval.set(evt.value().parse().ok()),
+            option { value: "", "Live refresh off" }
+            {LIVE_INTERVALS_SECS.iter().map(|it| rsx! {
+                option { value: "{it}", "Refresh every {it}s" }
+            })}
+        }
+    }
+}
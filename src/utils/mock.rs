@@ -0,0 +1,534 @@
+//! Offline backends for development, picked at compile time by cargo feature.
+//!
+//! - `mock` answers every read from `fixtures/backend.json` and rejects writes.
+//! - `record` talks to the configured service and captures each response, the navbar then offers the captured
+//!   responses as a `backend.json` download to drop into `fixtures/`.
+
+use serde::Deserialize;
+use serde::Serialize;
+use std::collections::HashMap;
+use zkp_service_helper::interface::AppConfig;
+use zkp_service_helper::interface::AutoSubmitProof;
+use zkp_service_helper::interface::ConciseTask;
+use zkp_service_helper::interface::Image;
+use zkp_service_helper::interface::ProverNode;
+use zkp_service_helper::interface::ProverNodeTimeRangeStats;
+use zkp_service_helper::interface::Round1Info;
+use zkp_service_helper::interface::Round2Info;
+use zkp_service_helper::interface::StatisticsInfo;
+use zkp_service_helper::interface::Subscription;
+use zkp_service_helper::interface::Task;
+use zkp_service_helper::interface::User;
+
+use crate::utils::service::ZkService;
+
+#[cfg(all(feature = "mock", feature = "record"))]
+compile_error!("The `mock` and `record` features can't be enabled together");
+
+/// The backend selected by the enabled cargo features, talking to the service at `url`.
+#[cfg(not(any(feature = "mock", feature = "record")))]
+pub fn backend(url: &str) -> impl ZkService {
+    zkp_service_helper::helper::ZkWasmServiceHelper::new(url.to_string())
+}
+
+/// The backend selected by the enabled cargo features, every environment serves the same fixtures.
+#[cfg(feature = "mock")]
+pub fn backend(_url: &str) -> impl ZkService {
+    MockServiceHelper::default()
+}
+
+/// The backend selected by the enabled cargo features, talking to the service at `url`.
+#[cfg(all(feature = "record", not(feature = "mock")))]
+pub fn backend(url: &str) -> impl ZkService {
+    RecordingServiceHelper::new(url.to_string())
+}
+
+/// Every response the mock backend can serve, one collection per kind of entity.
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Fixtures {
+    pub config: Option<AppConfig>,
+    pub statistics: Option<StatisticsInfo>,
+    pub images: Vec<Image>,
+    pub users: Vec<User>,
+    /// Keyed by lowercase user address.
+    pub subscriptions: HashMap<String, Subscription>,
+    pub tasks: Vec<Task>,
+    pub concise_tasks: Vec<ConciseTask>,
+    pub nodes: Vec<ProverNode>,
+    pub node_timerange_stats: Vec<ProverNodeTimeRangeStats>,
+    pub auto_submit_proofs: Vec<AutoSubmitProof>,
+    pub round1_info: Vec<Round1Info>,
+    pub round2_info: Vec<Round2Info>,
+    /// Keyed by task id.
+    pub external_host_tables: HashMap<String, Vec<u8>>,
+}
+
+#[cfg(feature = "mock")]
+pub use fixture::MockServiceHelper;
+
+#[cfg(feature = "mock")]
+mod fixture {
+    use super::Fixtures;
+    use zkp_service_helper::interface::AddImageParams;
+    use zkp_service_helper::interface::AddTaskResult;
+    use zkp_service_helper::interface::AppConfig;
+    use zkp_service_helper::interface::AutoSubmitProof;
+    use zkp_service_helper::interface::ConciseTask;
+    use zkp_service_helper::interface::Image;
+    use zkp_service_helper::interface::PaginationResult;
+    use zkp_service_helper::interface::ProverNode;
+    use zkp_service_helper::interface::ProverNodeTimeRangeStats;
+    use zkp_service_helper::interface::ProverNodeTimeRangeStatsParams;
+    use zkp_service_helper::interface::ProvingParams;
+    use zkp_service_helper::interface::ResetImageParams;
+    use zkp_service_helper::interface::Round1Info;
+    use zkp_service_helper::interface::Round2Info;
+    use zkp_service_helper::interface::StatisticsInfo;
+    use zkp_service_helper::interface::Subscription;
+    use zkp_service_helper::interface::Task;
+    use zkp_service_helper::interface::TaskStatus;
+    use zkp_service_helper::interface::TaskType;
+    use zkp_service_helper::interface::User;
+    use zkp_service_helper::interface::WithSignature;
+
+    const FIXTURES_JSON: &str = include_str!("../../fixtures/backend.json");
+
+    fn missing(what: &str) -> anyhow::Error {
+        anyhow::anyhow!("No {what} in the mock fixtures")
+    }
+
+    fn read_only() -> anyhow::Error {
+        anyhow::anyhow!("The mock backend is read-only")
+    }
+
+    /// Slices rows the way the service does, `start` is a row offset and `total` a page length.
+    fn paginate<T>(rows: Vec<T>, start: Option<u64>, total: Option<u64>) -> PaginationResult<Vec<T>> {
+        let count = rows.len() as u64;
+        let data = rows
+            .into_iter()
+            .skip(start.unwrap_or(0) as usize)
+            .take(total.map_or(usize::MAX, |it| it as usize))
+            .collect();
+        PaginationResult { data, total: count }
+    }
+
+    /// Serves the fixtures in place of the service, list queries filter and paginate like the real endpoints.
+    pub struct MockServiceHelper(Fixtures);
+
+    impl Default for MockServiceHelper {
+        fn default() -> Self {
+            Self(serde_json::from_str(FIXTURES_JSON).expect("Mock fixtures should parse"))
+        }
+    }
+
+    impl MockServiceHelper {
+        pub async fn query_statistics(&self) -> anyhow::Result<StatisticsInfo> {
+            self.0.statistics.clone().ok_or_else(|| missing("statistics"))
+        }
+
+        pub async fn query_config(&self) -> anyhow::Result<AppConfig> {
+            self.0.config.clone().ok_or_else(|| missing("config"))
+        }
+
+        pub async fn query_image(&self, md5: String) -> anyhow::Result<Option<Image>> {
+            Ok(self.0.images.iter().find(|it| it.md5.eq_ignore_ascii_case(&md5)).cloned())
+        }
+
+        pub async fn query_user(&self, address: String) -> anyhow::Result<Option<User>> {
+            Ok(self
+                .0
+                .users
+                .iter()
+                .find(|it| it.user_address.eq_ignore_ascii_case(&address))
+                .cloned())
+        }
+
+        pub async fn query_user_subscription(&self, address: String) -> anyhow::Result<Option<Subscription>> {
+            Ok(self.0.subscriptions.get(&address.to_lowercase()).cloned())
+        }
+
+        pub async fn query_task_from_id(&self, id: String) -> anyhow::Result<Option<Task>> {
+            Ok(self.0.tasks.iter().find(|it| it._id.oid == id).cloned())
+        }
+
+        #[allow(clippy::too_many_arguments)]
+        pub async fn query_concise_tasks(
+            &self,
+            user_address: Option<String>,
+            md5: Option<String>,
+            id: Option<String>,
+            tasktype: Option<TaskType>,
+            status: Option<TaskStatus>,
+            start: Option<u64>,
+            total: Option<u64>,
+        ) -> anyhow::Result<PaginationResult<Vec<ConciseTask>>> {
+            let rows = self
+                .0
+                .concise_tasks
+                .iter()
+                .filter(|it| {
+                    user_address
+                        .as_ref()
+                        .is_none_or(|addr| it.user_address.eq_ignore_ascii_case(addr))
+                        && md5.as_ref().is_none_or(|md5| it.md5.eq_ignore_ascii_case(md5))
+                        && id.as_ref().is_none_or(|id| it._id.oid == *id)
+                        && tasktype.as_ref().is_none_or(|tt| it.task_type == *tt)
+                        && status.as_ref().is_none_or(|st| it.status == *st)
+                })
+                .cloned()
+                .collect();
+            Ok(paginate(rows, start, total))
+        }
+
+        pub async fn query_node_statistics(
+            &self,
+            address: Option<String>,
+            start: Option<u64>,
+            total: Option<u64>,
+        ) -> anyhow::Result<PaginationResult<Vec<ProverNode>>> {
+            let rows = self
+                .0
+                .nodes
+                .iter()
+                .filter(|it| address.as_ref().is_none_or(|addr| it.address.eq_ignore_ascii_case(addr)))
+                .cloned()
+                .collect();
+            Ok(paginate(rows, start, total))
+        }
+
+        /// Fixtures hold one set of stats per node, the requested time range is ignored.
+        pub async fn query_prover_node_timerange_stats(
+            &self,
+            params: ProverNodeTimeRangeStatsParams,
+        ) -> anyhow::Result<Vec<ProverNodeTimeRangeStats>> {
+            Ok(params
+                .ranges
+                .iter()
+                .filter_map(|range| {
+                    self.0
+                        .node_timerange_stats
+                        .iter()
+                        .find(|it| it.address.eq_ignore_ascii_case(&range.address))
+                        .cloned()
+                })
+                .collect())
+        }
+
+        // The batch queries below only page, their filters are not used anywhere in the app.
+
+        #[allow(clippy::too_many_arguments)]
+        pub async fn query_auto_submit_proofs(
+            &self,
+            _: Option<String>,
+            _: Option<String>,
+            _: Option<String>,
+            _: Option<String>,
+            _: Option<u64>,
+            start: Option<u64>,
+            total: Option<u64>,
+        ) -> anyhow::Result<PaginationResult<Vec<AutoSubmitProof>>> {
+            Ok(paginate(self.0.auto_submit_proofs.clone(), start, total))
+        }
+
+        #[allow(clippy::too_many_arguments)]
+        pub async fn query_round1_info(
+            &self,
+            _: Option<String>,
+            _: Option<String>,
+            _: Option<String>,
+            _: Option<String>,
+            _: Option<String>,
+            _: Option<u64>,
+            start: Option<u64>,
+            total: Option<u64>,
+        ) -> anyhow::Result<PaginationResult<Vec<Round1Info>>> {
+            Ok(paginate(self.0.round1_info.clone(), start, total))
+        }
+
+        #[allow(clippy::too_many_arguments)]
+        pub async fn query_round2_info(
+            &self,
+            _: Option<String>,
+            _: Option<String>,
+            _: Option<String>,
+            _: Option<String>,
+            _: Option<u64>,
+            start: Option<u64>,
+            total: Option<u64>,
+        ) -> anyhow::Result<PaginationResult<Vec<Round2Info>>> {
+            Ok(paginate(self.0.round2_info.clone(), start, total))
+        }
+
+        pub async fn query_task_external_host_table(&self, id: String) -> anyhow::Result<Vec<u8>> {
+            self.0
+                .external_host_tables
+                .get(&id)
+                .cloned()
+                .ok_or_else(|| missing(&format!("external host table for task {id}")))
+        }
+
+        pub async fn add_new_wasm_image(&self, _: WithSignature<AddImageParams>) -> anyhow::Result<AddTaskResult> {
+            Err(read_only())
+        }
+
+        pub async fn add_proving_task(&self, _: WithSignature<ProvingParams>) -> anyhow::Result<AddTaskResult> {
+            Err(read_only())
+        }
+
+        pub async fn add_reset_task(&self, _: WithSignature<ResetImageParams>) -> anyhow::Result<AddTaskResult> {
+            Err(read_only())
+        }
+    }
+}
+
+#[cfg(feature = "record")]
+pub use record::save_recording;
+#[cfg(feature = "record")]
+pub use record::RecordingServiceHelper;
+
+#[cfg(feature = "record")]
+mod record {
+    use super::Fixtures;
+    use std::cell::RefCell;
+    use zkp_service_helper::helper::ZkWasmServiceHelper;
+    use zkp_service_helper::interface::AddImageParams;
+    use zkp_service_helper::interface::AddTaskResult;
+    use zkp_service_helper::interface::AppConfig;
+    use zkp_service_helper::interface::AutoSubmitProof;
+    use zkp_service_helper::interface::ConciseTask;
+    use zkp_service_helper::interface::Image;
+    use zkp_service_helper::interface::PaginationResult;
+    use zkp_service_helper::interface::ProverNode;
+    use zkp_service_helper::interface::ProverNodeTimeRangeStats;
+    use zkp_service_helper::interface::ProverNodeTimeRangeStatsParams;
+    use zkp_service_helper::interface::ProvingParams;
+    use zkp_service_helper::interface::ResetImageParams;
+    use zkp_service_helper::interface::Round1Info;
+    use zkp_service_helper::interface::Round2Info;
+    use zkp_service_helper::interface::StatisticsInfo;
+    use zkp_service_helper::interface::Subscription;
+    use zkp_service_helper::interface::Task;
+    use zkp_service_helper::interface::TaskStatus;
+    use zkp_service_helper::interface::TaskType;
+    use zkp_service_helper::interface::User;
+    use zkp_service_helper::interface::WithSignature;
+
+    use crate::utils::download::download_bytes;
+
+    thread_local! {
+        static RECORDED: RefCell<Fixtures> = RefCell::new(Fixtures::default());
+    }
+
+    fn record(apply: impl FnOnce(&mut Fixtures)) {
+        RECORDED.with_borrow_mut(apply);
+    }
+
+    /// Replaces the row that `same` matches or appends it, so repeated queries don't duplicate fixtures.
+    fn upsert<T>(rows: &mut Vec<T>, row: T, same: impl Fn(&T, &T) -> bool) {
+        match rows.iter_mut().find(|it| same(it, &row)) {
+            Some(it) => *it = row,
+            None => rows.push(row),
+        }
+    }
+
+    /// Downloads everything captured so far as a fixtures file for the mock backend.
+    pub fn save_recording() {
+        let res = RECORDED
+            .with_borrow(serde_json::to_vec_pretty)
+            .map_err(|e| e.to_string())
+            .and_then(|bytes| download_bytes("backend.json", &bytes, "application/json").map_err(|e| format!("{e:?}")));
+        if let Err(e) = res {
+            tracing::error!("{e}");
+        }
+    }
+
+    /// The real service helper, recording each successful read into the fixtures.
+    pub struct RecordingServiceHelper(ZkWasmServiceHelper);
+
+    impl RecordingServiceHelper {
+        pub fn new(endpoint: String) -> Self {
+            Self(ZkWasmServiceHelper::new(endpoint))
+        }
+
+        pub async fn query_statistics(&self) -> anyhow::Result<StatisticsInfo> {
+            let res = self.0.query_statistics().await?;
+            record(|f| f.statistics = Some(res.clone()));
+            Ok(res)
+        }
+
+        pub async fn query_config(&self) -> anyhow::Result<AppConfig> {
+            let res = self.0.query_config().await?;
+            record(|f| f.config = Some(res.clone()));
+            Ok(res)
+        }
+
+        pub async fn query_image(&self, md5: String) -> anyhow::Result<Option<Image>> {
+            let res = self.0.query_image(md5).await?;
+            if let Some(image) = res.clone() {
+                record(|f| upsert(&mut f.images, image, |a, b| a.md5 == b.md5));
+            }
+            Ok(res)
+        }
+
+        pub async fn query_user(&self, address: String) -> anyhow::Result<Option<User>> {
+            let res = self.0.query_user(address).await?;
+            if let Some(user) = res.clone() {
+                record(|f| upsert(&mut f.users, user, |a, b| a.user_address == b.user_address));
+            }
+            Ok(res)
+        }
+
+        pub async fn query_user_subscription(&self, address: String) -> anyhow::Result<Option<Subscription>> {
+            let res = self.0.query_user_subscription(address.clone()).await?;
+            if let Some(sub) = res.clone() {
+                record(|f| {
+                    f.subscriptions.insert(address.to_lowercase(), sub);
+                });
+            }
+            Ok(res)
+        }
+
+        pub async fn query_task_from_id(&self, id: String) -> anyhow::Result<Option<Task>> {
+            let res = self.0.query_task_from_id(id).await?;
+            if let Some(task) = res.clone() {
+                record(|f| upsert(&mut f.tasks, task, |a, b| a._id == b._id));
+            }
+            Ok(res)
+        }
+
+        #[allow(clippy::too_many_arguments)]
+        pub async fn query_concise_tasks(
+            &self,
+            user_address: Option<String>,
+            md5: Option<String>,
+            id: Option<String>,
+            tasktype: Option<TaskType>,
+            status: Option<TaskStatus>,
+            start: Option<u64>,
+            total: Option<u64>,
+        ) -> anyhow::Result<PaginationResult<Vec<ConciseTask>>> {
+            let res = self
+                .0
+                .query_concise_tasks(user_address, md5, id, tasktype, status, start, total)
+                .await?;
+            record(|f| {
+                for task in res.data.iter().cloned() {
+                    upsert(&mut f.concise_tasks, task, |a, b| a._id == b._id);
+                }
+            });
+            Ok(res)
+        }
+
+        pub async fn query_node_statistics(
+            &self,
+            address: Option<String>,
+            start: Option<u64>,
+            total: Option<u64>,
+        ) -> anyhow::Result<PaginationResult<Vec<ProverNode>>> {
+            let res = self.0.query_node_statistics(address, start, total).await?;
+            record(|f| {
+                for node in res.data.iter().cloned() {
+                    upsert(&mut f.nodes, node, |a, b| a.address == b.address);
+                }
+            });
+            Ok(res)
+        }
+
+        pub async fn query_prover_node_timerange_stats(
+            &self,
+            params: ProverNodeTimeRangeStatsParams,
+        ) -> anyhow::Result<Vec<ProverNodeTimeRangeStats>> {
+            let res = self.0.query_prover_node_timerange_stats(params).await?;
+            record(|f| {
+                for stats in res.iter().cloned() {
+                    upsert(&mut f.node_timerange_stats, stats, |a, b| a.address == b.address);
+                }
+            });
+            Ok(res)
+        }
+
+        #[allow(clippy::too_many_arguments)]
+        pub async fn query_auto_submit_proofs(
+            &self,
+            a: Option<String>,
+            b: Option<String>,
+            c: Option<String>,
+            d: Option<String>,
+            e: Option<u64>,
+            start: Option<u64>,
+            total: Option<u64>,
+        ) -> anyhow::Result<PaginationResult<Vec<AutoSubmitProof>>> {
+            let res = self.0.query_auto_submit_proofs(a, b, c, d, e, start, total).await?;
+            record(|f| {
+                for row in res.data.iter().cloned() {
+                    upsert(&mut f.auto_submit_proofs, row, |a, b| a._id == b._id);
+                }
+            });
+            Ok(res)
+        }
+
+        #[allow(clippy::too_many_arguments)]
+        pub async fn query_round1_info(
+            &self,
+            a: Option<String>,
+            b: Option<String>,
+            c: Option<String>,
+            d: Option<String>,
+            e: Option<String>,
+            g: Option<u64>,
+            start: Option<u64>,
+            total: Option<u64>,
+        ) -> anyhow::Result<PaginationResult<Vec<Round1Info>>> {
+            let res = self.0.query_round1_info(a, b, c, d, e, g, start, total).await?;
+            record(|f| {
+                for row in res.data.iter().cloned() {
+                    upsert(&mut f.round1_info, row, |a, b| a._id == b._id);
+                }
+            });
+            Ok(res)
+        }
+
+        #[allow(clippy::too_many_arguments)]
+        pub async fn query_round2_info(
+            &self,
+            a: Option<String>,
+            b: Option<String>,
+            c: Option<String>,
+            d: Option<String>,
+            e: Option<u64>,
+            start: Option<u64>,
+            total: Option<u64>,
+        ) -> anyhow::Result<PaginationResult<Vec<Round2Info>>> {
+            let res = self.0.query_round2_info(a, b, c, d, e, start, total).await?;
+            record(|f| {
+                for row in res.data.iter().cloned() {
+                    upsert(&mut f.round2_info, row, |a, b| a._id == b._id);
+                }
+            });
+            Ok(res)
+        }
+
+        pub async fn query_task_external_host_table(&self, id: String) -> anyhow::Result<Vec<u8>> {
+            let res = self.0.query_task_external_host_table(id.clone()).await?;
+            record(|f| {
+                f.external_host_tables.insert(id, res.clone());
+            });
+            Ok(res)
+        }
+
+        // Writes go straight through, fixtures only capture reads.
+
+        pub async fn add_new_wasm_image(&self, params: WithSignature<AddImageParams>) -> anyhow::Result<AddTaskResult> {
+            self.0.add_new_wasm_image(params).await
+        }
+
+        pub async fn add_proving_task(&self, params: WithSignature<ProvingParams>) -> anyhow::Result<AddTaskResult> {
+            self.0.add_proving_task(params).await
+        }
+
+        pub async fn add_reset_task(&self, params: WithSignature<ResetImageParams>) -> anyhow::Result<AddTaskResult> {
+            self.0.add_reset_task(params).await
+        }
+    }
+}
@@ -1,15 +1,42 @@
-use zkp_service_helper::interface::PaginationResult;
 use zkp_service_helper::interface::TaskStatus;
 
+use crate::utils::preferences::NumberFormat;
+
+pub mod cache;
 pub mod config;
 mod entry;
 pub use entry::AddressKind;
 pub use entry::AddressStyle;
+pub use entry::SortKey;
 pub use entry::ZkEntry;
 
 mod signal;
+pub use signal::use_query;
+pub use signal::Loadable;
+pub use signal::Query;
 pub use signal::QueryFunctionHandler;
 
+mod timestamp;
+pub use timestamp::parse_datetime_local;
+pub use timestamp::timestamp_formatted;
+pub use timestamp::zone_label;
+pub use timestamp::DateFormat;
+pub use timestamp::DisplayTimeZone;
+pub use timestamp::TimePreferences;
+pub use timestamp::TimestampStyle;
+
+pub mod download;
+pub mod environment;
+pub mod export;
+pub mod inputs;
+pub mod live;
+pub mod mock;
+pub mod preferences;
+pub mod proof;
+pub mod service;
+pub mod storage;
+pub mod theme;
+pub mod watch;
 pub mod web3_subscriber;
 
 pub trait UnwrapOrNA {
@@ -22,51 +49,35 @@ impl UnwrapOrNA for Option<String> {
     }
 }
 
-pub trait UnwrapOrEmpty<T: serde::Serialize> {
-    fn unwrap_or_empty(self) -> PaginationResult<Vec<T>>;
-}
-
-impl<T: serde::Serialize> UnwrapOrEmpty<T> for anyhow::Result<PaginationResult<Vec<T>>> {
-    fn unwrap_or_empty(self) -> PaginationResult<Vec<T>> {
-        self.unwrap_or_else(|e| {
-            tracing::error!("{e}");
-            PaginationResult { data: Vec::<T>::new(), total: 0 }
-        })
+/// Logs the error and falls back to "N/A", for values only shown as text.
+impl UnwrapOrNA for anyhow::Result<String> {
+    fn unwrap_or_na(&self) -> String {
+        match self {
+            Ok(it) => it.clone(),
+            Err(e) => {
+                tracing::error!("{e}");
+                "N/A".to_string()
+            }
+        }
     }
 }
 
-pub fn shorten_md5(it: String) -> String {
-    let l = it.len();
-    format!("{}...{}", &it[0..7], &it[l - 6..l])
-}
-
-pub fn shorten_address(it: &str) -> String {
-    let l = it.len();
-    format!("{}...{}", &it[0..8], &it[l - 4..l])
+/// Keeps the first `head` and last `tail` characters, failing if `it` is too short to have both.
+fn shorten(it: &str, head: usize, tail: usize) -> anyhow::Result<String> {
+    let start = it.get(..head);
+    let end = it.len().checked_sub(tail).and_then(|i| it.get(i..));
+    match (start, end) {
+        (Some(start), Some(end)) if it.len() >= head + tail => Ok(format!("{start}...{end}")),
+        _ => Err(anyhow::anyhow!("{it:?} is too short to shorten")),
+    }
 }
 
-#[derive(Clone, PartialEq)]
-pub enum TimestampStyle {
-    Simple,
-    Full,
+pub fn shorten_md5(it: &str) -> anyhow::Result<String> {
+    shorten(it, 7, 6)
 }
 
-pub fn timestamp_formatted(ts_str: &str, style: TimestampStyle) -> String {
-    let ts = ts_str
-        .parse::<chrono::DateTime<chrono::Utc>>()
-        .unwrap()
-        .with_timezone(&chrono::Local);
-    ts.format(match style {
-        TimestampStyle::Simple => {
-            if ts.date_naive() == chrono::Local::now().date_naive() {
-                "%-I:%M:%S %P"
-            } else {
-                "%d/%m/%Y"
-            }
-        }
-        TimestampStyle::Full => "%d/%m/%Y, %-I:%M:%S %P",
-    })
-    .to_string()
+pub fn shorten_address(it: &str) -> anyhow::Result<String> {
+    shorten(it, 8, 4)
 }
 
 pub fn webtime_to_rfc3339(time: web_time::SystemTime) -> String {
@@ -123,27 +134,138 @@ pub fn hex_to_num_string(inp: &str) -> Option<String> {
     })
 }
 
+/// Groups the digits of an integer, anything else is returned unchanged.
+pub fn format_number(num: &str, format: NumberFormat) -> String {
+    let digits = num.strip_prefix('-').unwrap_or(num);
+    if format == NumberFormat::Plain || digits.is_empty() || !digits.chars().all(|it| it.is_ascii_digit()) {
+        return num.to_string();
+    }
+    let grouped = digits
+        .as_bytes()
+        .rchunks(3)
+        .rev()
+        .map(|it| std::str::from_utf8(it).unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join(",");
+    format!("{}{grouped}", &num[..num.len() - digits.len()])
+}
+
+/// The theme token for a status pill, defined per theme in `theme.css`.
 pub fn task_status_to_background_color(status: TaskStatus) -> &'static str {
     match status {
-        TaskStatus::Pending => "#CA9B00",
-        TaskStatus::Processing => "#CA9B00",
-        TaskStatus::DryRunSuccess => "#CA9B00",
-        TaskStatus::DryRunFailed => "#CA9B00",
-        TaskStatus::Done => "#3E8166",
-        TaskStatus::Fail => "#DD6B00",
-        TaskStatus::Unprovable => "#894E50",
-        TaskStatus::Stale => "#636363",
+        TaskStatus::Pending => "var(--status-pending)",
+        TaskStatus::Processing => "var(--status-pending)",
+        TaskStatus::DryRunSuccess => "var(--status-pending)",
+        TaskStatus::DryRunFailed => "var(--status-pending)",
+        TaskStatus::Done => "var(--status-done)",
+        TaskStatus::Fail => "var(--status-fail)",
+        TaskStatus::Unprovable => "var(--status-unprovable)",
+        TaskStatus::Stale => "var(--status-stale)",
     }
 }
 
-pub fn enum_to_string<T: serde::Serialize>(obj: &T) -> String {
-    serde_json::to_string(obj)
-        .expect("Enum object should Serialize")
-        .trim_start_matches('"')
-        .trim_end_matches('"')
-        .to_string()
+/// The serialized name of a unit variant, failing for values that don't serialize to a string.
+pub fn enum_to_string<T: serde::Serialize>(obj: &T) -> anyhow::Result<String> {
+    match serde_json::to_value(obj)? {
+        serde_json::Value::String(it) => Ok(it),
+        other => Err(anyhow::anyhow!("{other} is not a unit variant")),
+    }
 }
 
-pub fn enum_from_string<T: serde::de::DeserializeOwned>(str: &str) -> T {
-    serde_json::from_str::<T>(&format!("\"{str}\"")).expect("Enum string should Deserialize")
+/// The serialized name of a unit enum, which always has one. Anything else shows as N/A.
+pub fn enum_label<T: serde::Serialize>(obj: &T) -> String {
+    enum_to_string(obj).unwrap_or_na()
+}
+
+pub fn enum_from_string<T: serde::de::DeserializeOwned>(str: &str) -> anyhow::Result<T> {
+    serde_json::from_value::<T>(serde_json::Value::String(str.to_string()))
+        .map_err(|e| anyhow::anyhow!("Invalid value {str:?}: {e}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use zkp_service_helper::interface::TaskType;
+
+    #[test]
+    fn shortens_well_formed_values() {
+        assert_eq!(shorten_md5("3f9a1c7e5b2d8a4f6c0e9b1d7a3f5c8e").unwrap(), "3f9a1c7...3f5c8e");
+        assert_eq!(
+            shorten_address("0x7f3a9c2e41b8d5f06a1e93c4b27d8e5f9a0c1b2d").unwrap(),
+            "0x7f3a9c...1b2d"
+        );
+    }
+
+    #[test]
+    fn short_values_fail_to_shorten() {
+        assert!(shorten_md5("").is_err());
+        assert!(shorten_md5("3f9a1c7e5b2d").is_err());
+        assert!(shorten_address("0x7f3a").is_err());
+    }
+
+    #[test]
+    fn multibyte_values_fail_to_shorten() {
+        assert!(shorten_md5("ééééééééééééééé").is_err());
+        assert!(shorten_address("0x7f3a9€c2e41b8d5f06a1e93c4b27d8e5f9a0c1b2d").is_err());
+    }
+
+    #[test]
+    fn enums_round_trip_through_strings() {
+        assert_eq!(enum_to_string(&TaskStatus::DryRunSuccess).unwrap(), "DryRunSuccess");
+        assert!(enum_from_string::<TaskStatus>("DryRunSuccess").unwrap() == TaskStatus::DryRunSuccess);
+        assert!(enum_from_string::<TaskType>("Prove").unwrap() == TaskType::Prove);
+    }
+
+    #[test]
+    fn unknown_enum_strings_fail_to_parse() {
+        for it in ["", "All", "prove", "\"Prove\"", "Prove\\"] {
+            assert!(enum_from_string::<TaskType>(it).is_err(), "{it}");
+        }
+    }
+
+    #[test]
+    fn non_unit_values_fail_to_convert() {
+        assert!(enum_to_string(&1).is_err());
+        assert!(enum_to_string(&vec![TaskType::Setup]).is_err());
+    }
+
+    #[test]
+    fn options_convert_as_their_value() {
+        assert_eq!(enum_to_string(&Some(TaskType::Setup)).unwrap(), "Setup");
+        assert!(enum_to_string(&Option::<TaskType>::None).is_err());
+    }
+
+    #[test]
+    fn groups_integers() {
+        let cases = [
+            ("0", "0"),
+            ("999", "999"),
+            ("1000", "1,000"),
+            ("1234567", "1,234,567"),
+            ("-1234567", "-1,234,567"),
+            (
+                "340282366920938463463374607431768211455",
+                "340,282,366,920,938,463,463,374,607,431,768,211,455",
+            ),
+        ];
+        for (num, expected) in cases {
+            assert_eq!(format_number(num, NumberFormat::Grouped), expected);
+            assert_eq!(format_number(num, NumberFormat::Plain), num);
+        }
+    }
+
+    #[test]
+    fn leaves_non_integers_ungrouped() {
+        for num in ["", "-", "N/A", "1234.5", "1e10", "0x1234", "１２３４"] {
+            assert_eq!(format_number(num, NumberFormat::Grouped), num);
+        }
+    }
+
+    #[test]
+    fn errors_fall_back_to_na() {
+        assert_eq!(enum_to_string(&1).unwrap_or_na(), "N/A");
+        assert_eq!(enum_to_string(&TaskType::Reset).unwrap_or_na(), "Reset");
+        assert_eq!(enum_label(&TaskType::Reset), "Reset");
+        assert_eq!(enum_label(&1), "N/A");
+    }
 }
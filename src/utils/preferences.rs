@@ -0,0 +1,130 @@
+use dioxus::prelude::*;
+
+use crate::utils::storage;
+use crate::utils::theme::use_theme;
+use crate::utils::theme::Theme;
+use crate::utils::TimePreferences;
+
+const STORAGE_KEY: &str = "preferences";
+/// Where the environment and the time preferences were kept before they moved into `Preferences`.
+const LEGACY_ENVIRONMENT_KEY: &str = "environment";
+const LEGACY_TIME_KEY: &str = "time-preferences";
+
+#[derive(Clone, Copy, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub enum NumberFormat {
+    /// `1234567`
+    #[default]
+    Plain,
+    /// `1,234,567`
+    Grouped,
+}
+
+/// Everything configurable from the settings page, kept in local storage and provided as context by `App`.
+#[derive(Clone, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct Preferences {
+    /// Rows per page paginated tables start with, each table's own default when `None`.
+    pub page_size: Option<u64>,
+    pub time: TimePreferences,
+    pub theme: Theme,
+    pub number_format: NumberFormat,
+    /// Name of the selected environment, the configured default when `None`.
+    pub environment: Option<String>,
+    pub hide_private_inputs: bool,
+}
+
+impl Preferences {
+    pub fn to_json(&self) -> anyhow::Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    /// Fields missing from `raw` keep their defaults, so files exported by older versions still import.
+    pub fn from_json(raw: &[u8]) -> anyhow::Result<Self> {
+        serde_json::from_slice(raw).map_err(|e| anyhow::anyhow!("Not a settings file: {e}"))
+    }
+
+    /// The stored preferences, or when there are none yet the values saved under the legacy keys.
+    fn migrate(stored: Option<Self>, environment: Option<String>, time: Option<TimePreferences>) -> Self {
+        stored.unwrap_or_else(|| Self {
+            environment,
+            time: time.unwrap_or_default(),
+            ..Default::default()
+        })
+    }
+}
+
+/// Loads the preferences, carrying over and then deleting the values older versions kept under their own keys.
+fn load() -> Preferences {
+    let prefs = Preferences::migrate(
+        storage::load(STORAGE_KEY),
+        storage::load(LEGACY_ENVIRONMENT_KEY),
+        storage::load(LEGACY_TIME_KEY),
+    );
+    storage::save(STORAGE_KEY, &prefs);
+    storage::remove(LEGACY_ENVIRONMENT_KEY);
+    storage::remove(LEGACY_TIME_KEY);
+    prefs
+}
+
+pub fn use_preferences_provider() -> Signal<Preferences> {
+    let prefs = use_context_provider(|| Signal::new(load()));
+    use_effect(move || storage::save(STORAGE_KEY, &*prefs.read()));
+    use_theme(prefs);
+    prefs
+}
+
+pub fn use_preferences() -> Signal<Preferences> {
+    use_context::<Signal<Preferences>>()
+}
+
+/// The preferences provided by `App` for code that renders outside of a hook, defaults outside of it.
+pub fn preferences() -> Preferences {
+    try_consume_context::<Signal<Preferences>>().map(|it| it()).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::DateFormat;
+
+    #[test]
+    fn round_trips_through_json() {
+        let mut prefs = Preferences {
+            page_size: Some(25),
+            number_format: NumberFormat::Grouped,
+            environment: Some("local".to_string()),
+            hide_private_inputs: true,
+            ..Default::default()
+        };
+        prefs.time.date_format = DateFormat::Iso;
+        let json = prefs.to_json().unwrap();
+        assert!(Preferences::from_json(json.as_bytes()).unwrap() == prefs);
+    }
+
+    #[test]
+    fn missing_fields_import_as_defaults() {
+        let prefs = Preferences::from_json(br#"{"page_size": 50}"#).unwrap();
+        assert_eq!(prefs.page_size, Some(50));
+        assert!(prefs.theme == Theme::System);
+        assert!(!prefs.hide_private_inputs);
+    }
+
+    #[test]
+    fn legacy_keys_migrate_only_without_stored_preferences() {
+        let time = TimePreferences { date_format: DateFormat::Iso, ..Default::default() };
+        let migrated = Preferences::migrate(None, Some("local".to_string()), Some(time));
+        assert_eq!(migrated.environment.as_deref(), Some("local"));
+        assert!(migrated.time == time);
+
+        let stored = Preferences { page_size: Some(25), ..Default::default() };
+        let kept = Preferences::migrate(Some(stored.clone()), Some("local".to_string()), Some(time));
+        assert!(kept == stored);
+    }
+
+    #[test]
+    fn rejects_malformed_files() {
+        for raw in ["", "42", "{\"page_size\": \"ten\"}", "{\"theme\": \"Purple\"}"] {
+            assert!(Preferences::from_json(raw.as_bytes()).is_err(), "{raw}");
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use zkp_service_helper::interface::Task;
+
+use crate::utils::bytes_to_bigint;
+
+/// Formats little endian 32 byte words as zero padded `uint256` hex literals.
+pub fn bytes_to_uint256_hex(data: &[u8]) -> Vec<String> {
+    bytes_to_bigint(data, Some(32))
+        .into_iter()
+        .map(|it| format!("0x{:0>64}", it.to_str_radix(16)))
+        .collect()
+}
+
+/// Selector of `verify(uint256[],uint256[],uint256[],uint256[][])` on the zkWasm verifier contract.
+const VERIFY_SELECTOR: [u8; 4] = [0xae, 0x02, 0x2c, 0xcd];
+
+fn abi_word(value: usize) -> [u8; 32] {
+    let mut word = [0u8; 32];
+    word[24..].copy_from_slice(&(value as u64).to_be_bytes());
+    word
+}
+
+/// ABI encodes little endian 32 byte words as a dynamic `uint256[]`.
+fn abi_uint256_array(data: &[u8]) -> Vec<u8> {
+    let words = bytes_to_bigint(data, Some(32));
+    let mut out = abi_word(words.len()).to_vec();
+    for it in words {
+        let be = it.to_bytes_be();
+        out.extend(std::iter::repeat_n(0u8, 32 - be.len()));
+        out.extend(be);
+    }
+    out
+}
+
+/// ABI encodes a tuple of dynamic values, each head holds the offset of its tail.
+fn abi_dynamic_tuple(items: Vec<Vec<u8>>) -> Vec<u8> {
+    let mut heads = vec![];
+    let mut tails = vec![];
+    let mut offset = 32 * items.len();
+    for item in items {
+        heads.extend(abi_word(offset));
+        offset += item.len();
+        tails.extend(item);
+    }
+    [heads, tails].concat()
+}
+
+/// The proof related byte fields of a task, in the order the task page shows them.
+#[derive(Clone, PartialEq)]
+pub struct ProofBundle {
+    pub task_id: String,
+    pub md5: String,
+    pub fields: Vec<(&'static str, Vec<u8>)>,
+}
+
+impl ProofBundle {
+    pub fn new(task: &Task) -> Self {
+        Self {
+            task_id: task._id.oid.clone(),
+            md5: task.md5.clone(),
+            fields: vec![
+                ("single_proof", task.single_proof.clone()),
+                ("proof", task.proof.clone()),
+                ("instances", task.instances.clone()),
+                ("shadow_instances", task.shadow_instances.clone()),
+                ("batch_instances", task.batch_instances.clone()),
+                ("aux", task.aux.clone()),
+            ],
+        }
+    }
+
+    pub fn field(&self, name: &str) -> &[u8] {
+        self.fields
+            .iter()
+            .find(|(it, _)| *it == name)
+            .map(|(_, bytes)| bytes.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Calldata for `verify(proof, verify_instance, aux, target_instance)`,
+    /// the batch instances verify the batched proof and the task instances are the single target.
+    pub fn verify_calldata(&self) -> Vec<u8> {
+        let target_instance = abi_word(1)
+            .into_iter()
+            .chain(abi_dynamic_tuple(vec![abi_uint256_array(self.field("instances"))]))
+            .collect();
+        let args = abi_dynamic_tuple(vec![
+            abi_uint256_array(self.field("proof")),
+            abi_uint256_array(self.field("batch_instances")),
+            abi_uint256_array(self.field("aux")),
+            target_instance,
+        ]);
+        [VERIFY_SELECTOR.to_vec(), args].concat()
+    }
+
+    /// All fields as `uint256` hex words, keyed by field name.
+    pub fn to_json(&self) -> anyhow::Result<String> {
+        let fields = self
+            .fields
+            .iter()
+            .map(|(name, bytes)| (name.to_string(), serde_json::json!(bytes_to_uint256_hex(bytes))))
+            .collect::<serde_json::Map<_, _>>();
+        Ok(serde_json::to_string_pretty(&serde_json::json!({
+            "task_id": self.task_id,
+            "md5": self.md5,
+            "fields": fields,
+        }))?)
+    }
+
+    /// One `uint256[]` array literal per non-empty field, ready to paste as verifier calldata.
+    pub fn to_solidity(&self) -> String {
+        self.fields
+            .iter()
+            .filter(|(_, bytes)| !bytes.is_empty())
+            .map(|(name, bytes)| {
+                let words = bytes_to_uint256_hex(bytes);
+                format!("// {name}: uint256[{}]\n[{}]\n", words.len(), words.join(","))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
@@ -0,0 +1,285 @@
+use dioxus::prelude::*;
+use futures::future::LocalBoxFuture;
+use std::future::Future;
+use std::rc::Rc;
+use zkp_service_helper::helper::ZkWasmServiceHelper;
+use zkp_service_helper::interface::AddImageParams;
+use zkp_service_helper::interface::AddTaskResult;
+use zkp_service_helper::interface::AppConfig;
+use zkp_service_helper::interface::AutoSubmitProof;
+use zkp_service_helper::interface::ConciseTask;
+use zkp_service_helper::interface::Image;
+use zkp_service_helper::interface::PaginationResult;
+use zkp_service_helper::interface::ProverNode;
+use zkp_service_helper::interface::ProverNodeTimeRangeStats;
+use zkp_service_helper::interface::ProverNodeTimeRangeStatsParams;
+use zkp_service_helper::interface::ProvingParams;
+use zkp_service_helper::interface::ResetImageParams;
+use zkp_service_helper::interface::Round1Info;
+use zkp_service_helper::interface::Round2Info;
+use zkp_service_helper::interface::StatisticsInfo;
+use zkp_service_helper::interface::Subscription;
+use zkp_service_helper::interface::Task;
+use zkp_service_helper::interface::TaskStatus;
+use zkp_service_helper::interface::TaskType;
+use zkp_service_helper::interface::User;
+use zkp_service_helper::interface::WithSignature;
+
+use crate::utils::cache::CachedServiceHelper;
+
+pub type ServiceFuture<'a, T> = LocalBoxFuture<'a, anyhow::Result<T>>;
+
+/// Every service query and submission the GUI makes, with the same arguments as `ZkWasmServiceHelper`.
+pub trait ZkService {
+    fn query_statistics(&self) -> ServiceFuture<'_, StatisticsInfo>;
+
+    fn query_config(&self) -> ServiceFuture<'_, AppConfig>;
+
+    fn query_image(&self, md5: String) -> ServiceFuture<'_, Option<Image>>;
+
+    fn query_user(&self, address: String) -> ServiceFuture<'_, Option<User>>;
+
+    fn query_user_subscription(&self, address: String) -> ServiceFuture<'_, Option<Subscription>>;
+
+    fn query_task_from_id(&self, id: String) -> ServiceFuture<'_, Option<Task>>;
+
+    #[allow(clippy::too_many_arguments)]
+    fn query_concise_tasks(
+        &self,
+        user_address: Option<String>,
+        md5: Option<String>,
+        id: Option<String>,
+        tasktype: Option<TaskType>,
+        status: Option<TaskStatus>,
+        start: Option<u64>,
+        total: Option<u64>,
+    ) -> ServiceFuture<'_, PaginationResult<Vec<ConciseTask>>>;
+
+    fn query_node_statistics(
+        &self,
+        address: Option<String>,
+        start: Option<u64>,
+        total: Option<u64>,
+    ) -> ServiceFuture<'_, PaginationResult<Vec<ProverNode>>>;
+
+    fn query_prover_node_timerange_stats(
+        &self,
+        params: ProverNodeTimeRangeStatsParams,
+    ) -> ServiceFuture<'_, Vec<ProverNodeTimeRangeStats>>;
+
+    #[allow(clippy::too_many_arguments)]
+    fn query_auto_submit_proofs(
+        &self,
+        a: Option<String>,
+        b: Option<String>,
+        c: Option<String>,
+        d: Option<String>,
+        e: Option<u64>,
+        start: Option<u64>,
+        total: Option<u64>,
+    ) -> ServiceFuture<'_, PaginationResult<Vec<AutoSubmitProof>>>;
+
+    #[allow(clippy::too_many_arguments)]
+    fn query_round1_info(
+        &self,
+        a: Option<String>,
+        b: Option<String>,
+        c: Option<String>,
+        d: Option<String>,
+        e: Option<String>,
+        f: Option<u64>,
+        start: Option<u64>,
+        total: Option<u64>,
+    ) -> ServiceFuture<'_, PaginationResult<Vec<Round1Info>>>;
+
+    #[allow(clippy::too_many_arguments)]
+    fn query_round2_info(
+        &self,
+        a: Option<String>,
+        b: Option<String>,
+        c: Option<String>,
+        d: Option<String>,
+        e: Option<u64>,
+        start: Option<u64>,
+        total: Option<u64>,
+    ) -> ServiceFuture<'_, PaginationResult<Vec<Round2Info>>>;
+
+    fn query_task_external_host_table(&self, id: String) -> ServiceFuture<'_, Vec<u8>>;
+
+    fn add_new_wasm_image(&self, params: WithSignature<AddImageParams>) -> ServiceFuture<'_, AddTaskResult>;
+
+    fn add_proving_task(&self, params: WithSignature<ProvingParams>) -> ServiceFuture<'_, AddTaskResult>;
+
+    fn add_reset_task(&self, params: WithSignature<ResetImageParams>) -> ServiceFuture<'_, AddTaskResult>;
+}
+
+/// Implements `ZkService` for a type whose inherent async methods already match it.
+macro_rules! forward_zk_service {
+    ($ty:ty) => {
+        impl ZkService for $ty {
+            fn query_statistics(&self) -> ServiceFuture<'_, StatisticsInfo> {
+                Box::pin(<$ty>::query_statistics(self))
+            }
+
+            fn query_config(&self) -> ServiceFuture<'_, AppConfig> {
+                Box::pin(<$ty>::query_config(self))
+            }
+
+            fn query_image(&self, md5: String) -> ServiceFuture<'_, Option<Image>> {
+                Box::pin(<$ty>::query_image(self, md5))
+            }
+
+            fn query_user(&self, address: String) -> ServiceFuture<'_, Option<User>> {
+                Box::pin(<$ty>::query_user(self, address))
+            }
+
+            fn query_user_subscription(&self, address: String) -> ServiceFuture<'_, Option<Subscription>> {
+                Box::pin(<$ty>::query_user_subscription(self, address))
+            }
+
+            fn query_task_from_id(&self, id: String) -> ServiceFuture<'_, Option<Task>> {
+                Box::pin(<$ty>::query_task_from_id(self, id))
+            }
+
+            fn query_concise_tasks(
+                &self,
+                user_address: Option<String>,
+                md5: Option<String>,
+                id: Option<String>,
+                tasktype: Option<TaskType>,
+                status: Option<TaskStatus>,
+                start: Option<u64>,
+                total: Option<u64>,
+            ) -> ServiceFuture<'_, PaginationResult<Vec<ConciseTask>>> {
+                Box::pin(<$ty>::query_concise_tasks(
+                    self,
+                    user_address,
+                    md5,
+                    id,
+                    tasktype,
+                    status,
+                    start,
+                    total,
+                ))
+            }
+
+            fn query_node_statistics(
+                &self,
+                address: Option<String>,
+                start: Option<u64>,
+                total: Option<u64>,
+            ) -> ServiceFuture<'_, PaginationResult<Vec<ProverNode>>> {
+                Box::pin(<$ty>::query_node_statistics(self, address, start, total))
+            }
+
+            fn query_prover_node_timerange_stats(
+                &self,
+                params: ProverNodeTimeRangeStatsParams,
+            ) -> ServiceFuture<'_, Vec<ProverNodeTimeRangeStats>> {
+                Box::pin(<$ty>::query_prover_node_timerange_stats(self, params))
+            }
+
+            fn query_auto_submit_proofs(
+                &self,
+                a: Option<String>,
+                b: Option<String>,
+                c: Option<String>,
+                d: Option<String>,
+                e: Option<u64>,
+                start: Option<u64>,
+                total: Option<u64>,
+            ) -> ServiceFuture<'_, PaginationResult<Vec<AutoSubmitProof>>> {
+                Box::pin(<$ty>::query_auto_submit_proofs(self, a, b, c, d, e, start, total))
+            }
+
+            fn query_round1_info(
+                &self,
+                a: Option<String>,
+                b: Option<String>,
+                c: Option<String>,
+                d: Option<String>,
+                e: Option<String>,
+                f: Option<u64>,
+                start: Option<u64>,
+                total: Option<u64>,
+            ) -> ServiceFuture<'_, PaginationResult<Vec<Round1Info>>> {
+                Box::pin(<$ty>::query_round1_info(self, a, b, c, d, e, f, start, total))
+            }
+
+            fn query_round2_info(
+                &self,
+                a: Option<String>,
+                b: Option<String>,
+                c: Option<String>,
+                d: Option<String>,
+                e: Option<u64>,
+                start: Option<u64>,
+                total: Option<u64>,
+            ) -> ServiceFuture<'_, PaginationResult<Vec<Round2Info>>> {
+                Box::pin(<$ty>::query_round2_info(self, a, b, c, d, e, start, total))
+            }
+
+            fn query_task_external_host_table(&self, id: String) -> ServiceFuture<'_, Vec<u8>> {
+                Box::pin(<$ty>::query_task_external_host_table(self, id))
+            }
+
+            fn add_new_wasm_image(&self, params: WithSignature<AddImageParams>) -> ServiceFuture<'_, AddTaskResult> {
+                Box::pin(<$ty>::add_new_wasm_image(self, params))
+            }
+
+            fn add_proving_task(&self, params: WithSignature<ProvingParams>) -> ServiceFuture<'_, AddTaskResult> {
+                Box::pin(<$ty>::add_proving_task(self, params))
+            }
+
+            fn add_reset_task(&self, params: WithSignature<ResetImageParams>) -> ServiceFuture<'_, AddTaskResult> {
+                Box::pin(<$ty>::add_reset_task(self, params))
+            }
+        }
+    };
+}
+
+forward_zk_service!(ZkWasmServiceHelper);
+forward_zk_service!(CachedServiceHelper);
+#[cfg(feature = "mock")]
+forward_zk_service!(crate::utils::mock::MockServiceHelper);
+#[cfg(all(feature = "record", not(feature = "mock")))]
+forward_zk_service!(crate::utils::mock::RecordingServiceHelper);
+
+/// The service client views query through, provided as context by `App` so it can be swapped for another backend.
+/// Queries read it reactively, so hooks that query through it rerun when the service is replaced.
+#[derive(Clone, Copy, PartialEq)]
+pub struct ZkClient(Signal<Rc<dyn ZkService>>);
+
+impl ZkClient {
+    pub fn new(service: impl ZkService + 'static) -> Self {
+        Self(Signal::new(Rc::new(service)))
+    }
+
+    /// Swaps in another service, rerunning every query that read the old one.
+    pub fn replace(mut self, service: impl ZkService + 'static) {
+        self.0.set(Rc::new(service));
+    }
+
+    pub fn get(&self) -> Rc<dyn ZkService> {
+        (self.0)()
+    }
+
+    /// The current service without subscribing, for one-off requests like form submissions.
+    pub fn peek(&self) -> Rc<dyn ZkService> {
+        self.0.peek().clone()
+    }
+
+    /// Runs `query` in a future that owns the current service, as `use_query` and `use_resource` need.
+    pub fn run<T, F>(&self, query: F) -> impl Future<Output = anyhow::Result<T>> + 'static
+    where
+        F: for<'a> FnOnce(&'a dyn ZkService) -> ServiceFuture<'a, T> + 'static,
+    {
+        let service = self.get();
+        async move { query(&*service).await }
+    }
+}
+
+/// The nearest service client provided above this component.
+pub fn use_zk() -> ZkClient {
+    use_context()
+}
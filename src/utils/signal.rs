@@ -1,24 +1,135 @@
 use dioxus::prelude::*;
+use std::cell::Cell;
+use std::future::Future;
+use std::rc::Rc;
+
+use crate::components::status::ErrorCard;
+use crate::components::status::Spinner;
+use crate::utils::live::use_live_tick;
+use crate::utils::service::use_zk;
+use crate::utils::service::ZkService;
+
+/// Data loaded from the service, a failure keeps its message so it isn't mistaken for missing data.
+#[derive(Clone, PartialEq)]
+pub enum Loadable<T> {
+    Loading,
+    Failed(String),
+    Ready(T),
+}
+
+impl<T> Loadable<T> {
+    pub fn ready(self) -> Option<T> {
+        match self {
+            Self::Ready(it) => Some(it),
+            _ => None,
+        }
+    }
+
+    /// Ready once both are, the first failure wins over loading.
+    pub fn and<U>(self, other: Loadable<U>) -> Loadable<(T, U)> {
+        match (self, other) {
+            (Self::Failed(e), _) | (_, Loadable::Failed(e)) => Loadable::Failed(e),
+            (Self::Ready(a), Loadable::Ready(b)) => Loadable::Ready((a, b)),
+            _ => Loadable::Loading,
+        }
+    }
+
+    /// A spinner while loading, an error card with a retry button on failure, otherwise `ready`.
+    pub fn render(self, mut onretry: impl FnMut() + 'static, ready: impl FnOnce(T) -> Element) -> Element {
+        match self {
+            Self::Loading => rsx! {
+                Spinner {}
+            },
+            Self::Failed(message) => rsx! {
+                ErrorCard { message, onretry: move |_| onretry() }
+            },
+            Self::Ready(it) => ready(it),
+        }
+    }
+}
+
+/// The state of a query started with `use_query` and a handle to retry it.
+pub struct Query<T: 'static> {
+    state: Signal<Loadable<T>>,
+    attempt: Signal<u64>,
+}
+
+impl<T> Clone for Query<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Query<T> {}
+
+impl<T> PartialEq for Query<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state && self.attempt == other.attempt
+    }
+}
+
+impl<T: Clone + 'static> Query<T> {
+    pub fn state(&self) -> Loadable<T> {
+        (self.state)()
+    }
+
+    pub fn ready(&self) -> Option<T> {
+        self.state().ready()
+    }
+
+    pub fn retry(&mut self) {
+        self.attempt += 1;
+    }
+
+    pub fn render(&self, ready: impl FnOnce(T) -> Element) -> Element {
+        let mut query = *self;
+        self.state().render(move || query.retry(), ready)
+    }
+}
+
+/// Runs `query` like `use_resource` and again on every live refresh tick.
+/// Live refreshes keep the current data on screen, any other re-run shows loading until it settles.
+pub fn use_query<T, Fut>(mut query: impl FnMut() -> Fut + 'static) -> Query<T>
+where
+    T: Clone + 'static,
+    Fut: Future<Output = anyhow::Result<T>> + 'static,
+{
+    let mut state = use_signal(|| Loadable::Loading);
+    let attempt = use_signal(|| 0u64);
+    let tick = use_live_tick();
+    let last_tick = use_hook(|| Rc::new(Cell::new(0u64)));
+    let _ = use_resource(move || {
+        attempt();
+        let refresh = tick() != last_tick.replace(tick());
+        if !refresh && !matches!(*state.peek(), Loadable::Loading) {
+            state.set(Loadable::Loading);
+        }
+        let fut = query();
+        async move {
+            state.set(match fut.await {
+                Ok(it) => Loadable::Ready(it),
+                Err(e) => {
+                    tracing::error!("{e}");
+                    Loadable::Failed(e.to_string())
+                }
+            });
+        }
+    });
+    Query { state, attempt }
+}
 
 pub trait QueryFunctionHandler {
     type Input: Clone + 'static = ();
-    type Data: serde::Serialize + Clone + PartialEq + Default + 'static;
-
-    async fn query(inp: Self::Input) -> anyhow::Result<Self::Data>;
-
-    fn fetch_resource(inp: Self::Input) -> Signal<Self::Data> {
-        let mut data = use_signal(Self::Data::default);
-        use_future(move || {
-            let inp_cp = inp.clone();
-            async move {
-                data.set(
-                    Self::query(inp_cp)
-                        .await
-                        .inspect_err(|e| tracing::error!("{e}"))
-                        .unwrap_or_else(|_| Self::Data::default()),
-                );
-            }
-        });
-        data
+    type Data: serde::Serialize + Clone + PartialEq + 'static;
+
+    async fn query(zk: Rc<dyn ZkService>, inp: Self::Input) -> anyhow::Result<Self::Data>;
+
+    /// Queries once, then again on every live refresh tick when a `LiveInterval` is provided.
+    fn fetch_resource(inp: Self::Input) -> Query<Self::Data> {
+        let zk = use_zk();
+        use_query(move || {
+            let (zk, inp_cp) = (zk.get(), inp.clone());
+            async move { Self::query(zk, inp_cp).await }
+        })
     }
 }
@@ -0,0 +1,37 @@
+use web_sys::window;
+use web_sys::Storage;
+
+const KEY_PREFIX: &str = "zkp-web-gui:";
+
+fn local_storage() -> Option<Storage> {
+    window()?.local_storage().ok().flatten()
+}
+
+/// Reads a JSON value from browser local storage, missing or malformed values read as `None`.
+pub fn load<T: serde::de::DeserializeOwned>(key: &str) -> Option<T> {
+    let raw = local_storage()?.get_item(&format!("{KEY_PREFIX}{key}")).ok().flatten()?;
+    serde_json::from_str(&raw).inspect_err(|e| tracing::error!("{key}: {e}")).ok()
+}
+
+pub fn save<T: serde::Serialize>(key: &str, value: &T) {
+    let Some(storage) = local_storage() else {
+        return;
+    };
+    let res = serde_json::to_string(value).map_err(|e| e.to_string()).and_then(|raw| {
+        storage
+            .set_item(&format!("{KEY_PREFIX}{key}"), &raw)
+            .map_err(|e| format!("{e:?}"))
+    });
+    if let Err(e) = res {
+        tracing::error!("{key}: {e}");
+    }
+}
+
+pub fn remove(key: &str) {
+    let Some(storage) = local_storage() else {
+        return;
+    };
+    if let Err(e) = storage.remove_item(&format!("{KEY_PREFIX}{key}")) {
+        tracing::error!("{key}: {e:?}");
+    }
+}
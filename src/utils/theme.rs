@@ -0,0 +1,117 @@
+use dioxus::prelude::*;
+use wasm_bindgen::closure::Closure;
+use wasm_bindgen::JsCast;
+use web_sys::MediaQueryList;
+
+use crate::utils::preferences::Preferences;
+
+#[derive(Clone, Copy, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub enum Theme {
+    /// Follows the browser's `prefers-color-scheme`.
+    #[default]
+    System,
+    Light,
+    Dark,
+}
+
+fn prefers_dark() -> Option<MediaQueryList> {
+    web_sys::window()?.match_media("(prefers-color-scheme: dark)").ok().flatten()
+}
+
+impl Theme {
+    /// Light or dark, `System` resolves to dark when the browser can't tell like the stylesheet does.
+    pub fn resolve(self) -> Self {
+        match self {
+            Self::System if prefers_dark().is_some_and(|it| !it.matches()) => Self::Light,
+            Self::System => Self::Dark,
+            it => it,
+        }
+    }
+}
+
+/// Sets `data-theme` on the root element, which picks the colour tokens in `theme.css`.
+fn apply(theme: Theme) {
+    let Some(root) = web_sys::window()
+        .and_then(|it| it.document())
+        .and_then(|it| it.document_element())
+    else {
+        return;
+    };
+    let name = match theme.resolve() {
+        Theme::Light => "light",
+        _ => "dark",
+    };
+    if let Err(e) = root.set_attribute("data-theme", name) {
+        tracing::error!("{e:?}");
+    }
+}
+
+/// Applies the preferred theme, and follows the system's while it's `System`.
+pub fn use_theme(prefs: Signal<Preferences>) {
+    use_effect(move || apply(prefs.read().theme));
+    use_hook(move || {
+        let Some(query) = prefers_dark() else {
+            return;
+        };
+        let onchange = Closure::<dyn Fn()>::new(move || apply(prefs.peek().theme));
+        query.set_onchange(Some(onchange.as_ref().unchecked_ref()));
+        // Lives as long as the page, like the app itself
+        onchange.forget();
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    const THEME_CSS: &str = include_str!("../../assets/styling/theme.css");
+
+    fn relative_luminance(hex: &str) -> f64 {
+        let hex = hex.trim_start_matches('#');
+        let channel = |i: usize| {
+            let c = u8::from_str_radix(&hex[i..i + 2], 16).expect("Status colours should be #rrggbb") as f64 / 255.0;
+            if c <= 0.03928 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * channel(0) + 0.7152 * channel(2) + 0.0722 * channel(4)
+    }
+
+    fn contrast(a: &str, b: &str) -> f64 {
+        let (a, b) = (relative_luminance(a), relative_luminance(b));
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// The `--name: value;` declarations of each theme block, in file order.
+    fn themes() -> Vec<Vec<(&'static str, &'static str)>> {
+        THEME_CSS
+            .split('}')
+            .filter(|block| block.contains("--status-text"))
+            .map(|block| {
+                block
+                    .lines()
+                    .filter_map(|line| line.trim().strip_prefix("--")?.strip_suffix(';')?.split_once(": "))
+                    .collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn themes_define_the_same_tokens() {
+        let themes = themes();
+        assert_eq!(themes.len(), 2);
+        let names = |theme: &Vec<(&'static str, &'static str)>| theme.iter().map(|(name, _)| *name).collect::<Vec<_>>();
+        assert_eq!(names(&themes[0]), names(&themes[1]));
+    }
+
+    #[test]
+    fn status_colours_have_enough_contrast() {
+        for theme in themes() {
+            let token = |name: &str| theme.iter().find(|(it, _)| *it == name).expect(name).1;
+            for status in ["pending", "done", "fail", "unprovable", "stale"] {
+                let ratio = contrast(token("status-text"), token(&format!("status-{status}")));
+                assert!(ratio >= 4.5, "{status} has contrast {ratio:.2}");
+            }
+        }
+    }
+}
@@ -0,0 +1,261 @@
+use chrono::DateTime;
+use chrono::Utc;
+
+#[derive(Clone, PartialEq)]
+pub enum TimestampStyle {
+    /// Date and time to the minute, for tables and summaries.
+    Simple,
+    /// Date and time to the second with the time zone, for detail pages.
+    Full,
+}
+
+#[derive(Clone, Copy, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub enum DisplayTimeZone {
+    #[default]
+    Local,
+    Utc,
+}
+
+#[derive(Clone, Copy, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+pub enum DateFormat {
+    /// `2024-09-23 14:45`
+    Iso,
+    /// `23/09/2024 2:45 pm`
+    #[default]
+    Regional,
+}
+
+/// How every timestamp entry is shown.
+#[derive(Clone, Copy, PartialEq, Default, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct TimePreferences {
+    pub time_zone: DisplayTimeZone,
+    pub date_format: DateFormat,
+    /// Show "4 min ago" instead of the absolute time, which moves to the tooltip.
+    pub relative: bool,
+}
+
+/// A timestamp as shown in a cell, `full` is the absolute time with its zone for the tooltip.
+#[derive(Clone, PartialEq, Debug)]
+pub struct FormattedTimestamp {
+    pub text: String,
+    pub full: String,
+}
+
+pub fn timestamp_formatted(
+    ts_str: &str,
+    style: TimestampStyle,
+    prefs: &TimePreferences,
+) -> anyhow::Result<FormattedTimestamp> {
+    let ts = ts_str
+        .parse::<DateTime<Utc>>()
+        .map_err(|e| anyhow::anyhow!("Invalid timestamp {ts_str:?}: {e}"))?;
+    Ok(format_at(ts, style, prefs, Utc::now()))
+}
+
+/// Reads a `datetime-local` input value as a time in `zone`, seconds and fractions of them are optional.
+pub fn parse_datetime_local(value: &str, zone: DisplayTimeZone) -> anyhow::Result<DateTime<Utc>> {
+    let naive = ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%dT%H:%M"]
+        .iter()
+        .find_map(|fmt| chrono::NaiveDateTime::parse_from_str(value, fmt).ok())
+        .ok_or_else(|| anyhow::anyhow!("Invalid date and time {value:?}"))?;
+    match zone {
+        DisplayTimeZone::Utc => Ok(naive.and_utc()),
+        DisplayTimeZone::Local => naive
+            .and_local_timezone(chrono::Local)
+            .earliest()
+            .map(|it| it.to_utc())
+            .ok_or_else(|| anyhow::anyhow!("{value:?} doesn't exist in the local time zone")),
+    }
+}
+
+/// Names the zone times are read and shown in, with the current offset for local time.
+pub fn zone_label(zone: DisplayTimeZone) -> String {
+    match zone {
+        DisplayTimeZone::Utc => "UTC".to_string(),
+        DisplayTimeZone::Local => format!("local time (UTC{})", chrono::Local::now().offset()),
+    }
+}
+
+fn format_at(
+    ts: DateTime<Utc>,
+    style: TimestampStyle,
+    prefs: &TimePreferences,
+    now: DateTime<Utc>,
+) -> FormattedTimestamp {
+    let full = absolute(ts, TimestampStyle::Full, prefs);
+    let text = if prefs.relative {
+        relative(ts, now)
+    } else {
+        match style {
+            TimestampStyle::Simple => absolute(ts, TimestampStyle::Simple, prefs),
+            TimestampStyle::Full => full.clone(),
+        }
+    };
+    FormattedTimestamp { text, full }
+}
+
+fn absolute(ts: DateTime<Utc>, style: TimestampStyle, prefs: &TimePreferences) -> String {
+    let fmt = match (prefs.date_format, &style) {
+        (DateFormat::Iso, TimestampStyle::Simple) => "%Y-%m-%d %H:%M",
+        (DateFormat::Iso, TimestampStyle::Full) => "%Y-%m-%d %H:%M:%S",
+        (DateFormat::Regional, TimestampStyle::Simple) => "%d/%m/%Y %-I:%M %P",
+        (DateFormat::Regional, TimestampStyle::Full) => "%d/%m/%Y, %-I:%M:%S %P",
+    };
+    let zone = match (prefs.time_zone, style) {
+        (_, TimestampStyle::Simple) => "",
+        (DisplayTimeZone::Utc, TimestampStyle::Full) => " UTC",
+        (DisplayTimeZone::Local, TimestampStyle::Full) => " UTC%:z",
+    };
+    let fmt = format!("{fmt}{zone}");
+    match prefs.time_zone {
+        DisplayTimeZone::Utc => ts.format(&fmt).to_string(),
+        DisplayTimeZone::Local => ts.with_timezone(&chrono::Local).format(&fmt).to_string(),
+    }
+}
+
+fn relative(ts: DateTime<Utc>, now: DateTime<Utc>) -> String {
+    const MINUTE: i64 = 60;
+    const HOUR: i64 = 60 * MINUTE;
+    const DAY: i64 = 24 * HOUR;
+    const MONTH: i64 = 30 * DAY;
+    const YEAR: i64 = 365 * DAY;
+
+    let secs = (now - ts).num_seconds();
+    let (n, unit) = match secs.abs() {
+        s if s < MINUTE => return "just now".to_string(),
+        s if s < HOUR => (s / MINUTE, "min"),
+        s if s < DAY => (s / HOUR, "hour"),
+        s if s < MONTH => (s / DAY, "day"),
+        s if s < YEAR => (s / MONTH, "month"),
+        s => (s / YEAR, "year"),
+    };
+    let plural = if n == 1 || unit == "min" { "" } else { "s" };
+    if secs < 0 {
+        format!("in {n} {unit}{plural}")
+    } else {
+        format!("{n} {unit}{plural} ago")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TS: &str = "2024-09-23T14:45:09Z";
+
+    fn format_utc(style: TimestampStyle, date_format: DateFormat) -> FormattedTimestamp {
+        let prefs = TimePreferences {
+            time_zone: DisplayTimeZone::Utc,
+            date_format,
+            relative: false,
+        };
+        timestamp_formatted(TS, style, &prefs).unwrap()
+    }
+
+    #[test]
+    fn formats_regional_timestamps() {
+        assert_eq!(
+            format_utc(TimestampStyle::Simple, DateFormat::Regional).text,
+            "23/09/2024 2:45 pm"
+        );
+        assert_eq!(
+            format_utc(TimestampStyle::Full, DateFormat::Regional).text,
+            "23/09/2024, 2:45:09 pm UTC"
+        );
+    }
+
+    #[test]
+    fn formats_iso_timestamps() {
+        assert_eq!(format_utc(TimestampStyle::Simple, DateFormat::Iso).text, "2024-09-23 14:45");
+        assert_eq!(
+            format_utc(TimestampStyle::Full, DateFormat::Iso).text,
+            "2024-09-23 14:45:09 UTC"
+        );
+    }
+
+    #[test]
+    fn local_timestamps_show_their_offset() {
+        let prefs = TimePreferences { date_format: DateFormat::Iso, ..Default::default() };
+        let ts = timestamp_formatted(TS, TimestampStyle::Full, &prefs).unwrap();
+        let local = TS.parse::<DateTime<Utc>>().unwrap().with_timezone(&chrono::Local);
+        assert_eq!(ts.text, local.format("%Y-%m-%d %H:%M:%S UTC%:z").to_string());
+        assert!(ts.text.ends_with(&format!(" UTC{}", local.offset())));
+    }
+
+    #[test]
+    fn datetime_local_values_parse_with_or_without_seconds() {
+        let ts = TS.parse::<DateTime<Utc>>().unwrap();
+        let minute = ts - chrono::Duration::seconds(9);
+        assert_eq!(parse_datetime_local("2024-09-23T14:45", DisplayTimeZone::Utc).unwrap(), minute);
+        assert_eq!(parse_datetime_local("2024-09-23T14:45:09", DisplayTimeZone::Utc).unwrap(), ts);
+        assert_eq!(
+            parse_datetime_local("2024-09-23T14:45:09.500", DisplayTimeZone::Utc).unwrap(),
+            ts + chrono::Duration::milliseconds(500)
+        );
+        for value in ["", "2024-09-23", "2024-09-23 14:45", "2024-13-45T99:99", "yesterday"] {
+            assert!(parse_datetime_local(value, DisplayTimeZone::Utc).is_err(), "{value}");
+        }
+    }
+
+    #[test]
+    fn datetime_local_values_read_in_the_chosen_zone() {
+        let local = parse_datetime_local("2024-09-23T14:45:09", DisplayTimeZone::Local).unwrap();
+        let offset = local.with_timezone(&chrono::Local).offset().local_minus_utc();
+        let utc = parse_datetime_local("2024-09-23T14:45:09", DisplayTimeZone::Utc).unwrap();
+        assert_eq!(utc - local, chrono::Duration::seconds(offset.into()));
+    }
+
+    #[test]
+    fn simple_style_doesnt_depend_on_the_current_date() {
+        let ts = TS.parse::<DateTime<Utc>>().unwrap();
+        let prefs = TimePreferences {
+            time_zone: DisplayTimeZone::Utc,
+            ..Default::default()
+        };
+        let same_day = format_at(ts, TimestampStyle::Simple, &prefs, ts);
+        let later = format_at(ts, TimestampStyle::Simple, &prefs, ts + chrono::Duration::days(40));
+        assert_eq!(same_day, later);
+    }
+
+    #[test]
+    fn relative_times_keep_the_absolute_time_in_full() {
+        let ts = TS.parse::<DateTime<Utc>>().unwrap();
+        let prefs = TimePreferences {
+            time_zone: DisplayTimeZone::Utc,
+            date_format: DateFormat::Iso,
+            relative: true,
+        };
+        let formatted = format_at(ts, TimestampStyle::Simple, &prefs, ts + chrono::Duration::minutes(4));
+        assert_eq!(formatted.text, "4 min ago");
+        assert_eq!(formatted.full, "2024-09-23 14:45:09 UTC");
+    }
+
+    #[test]
+    fn relative_times() {
+        let ts = TS.parse::<DateTime<Utc>>().unwrap();
+        let cases = [
+            (chrono::Duration::seconds(30), "just now"),
+            (chrono::Duration::minutes(1), "1 min ago"),
+            (chrono::Duration::minutes(59), "59 min ago"),
+            (chrono::Duration::hours(1), "1 hour ago"),
+            (chrono::Duration::hours(5), "5 hours ago"),
+            (chrono::Duration::days(3), "3 days ago"),
+            (chrono::Duration::days(65), "2 months ago"),
+            (chrono::Duration::days(800), "2 years ago"),
+            (chrono::Duration::minutes(-10), "in 10 min"),
+            (chrono::Duration::days(-1), "in 1 day"),
+        ];
+        for (offset, expected) in cases {
+            assert_eq!(relative(ts, ts + offset), expected);
+        }
+    }
+
+    #[test]
+    fn malformed_timestamps_fail_to_format() {
+        let prefs = TimePreferences::default();
+        for ts in ["", "yesterday", "2024-09-23", "2024-13-45T99:99:99Z", "1727102709"] {
+            assert!(timestamp_formatted(ts, TimestampStyle::Full, &prefs).is_err(), "{ts}");
+        }
+    }
+}
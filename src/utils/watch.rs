@@ -0,0 +1,139 @@
+use dioxus::prelude::*;
+use gloo_timers::future::TimeoutFuture;
+use wasm_bindgen_futures::JsFuture;
+use web_sys::Notification;
+use web_sys::NotificationOptions;
+use web_sys::NotificationPermission;
+use zkp_service_helper::interface::TaskStatus;
+
+use crate::utils::enum_label;
+use crate::utils::service::use_zk;
+use crate::utils::storage;
+
+const STORAGE_KEY: &str = "watched-tasks";
+const POLL_MS: u32 = 10_000;
+
+#[derive(Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct WatchedTask {
+    pub id: String,
+    pub status: TaskStatus,
+}
+
+impl WatchedTask {
+    pub fn is_active(&self) -> bool {
+        !is_terminal(&self.status)
+    }
+}
+
+/// Every status a task can't leave again, including `DryRunFailed` and any the service adds later,
+/// so a watched task is never left unpolled without a notification.
+fn is_terminal(status: &TaskStatus) -> bool {
+    !matches!(status, TaskStatus::Pending | TaskStatus::Processing | TaskStatus::DryRunSuccess)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub struct WatchList(pub Signal<Vec<WatchedTask>>);
+
+impl WatchList {
+    pub fn contains(&self, id: &str) -> bool {
+        self.0.read().iter().any(|it| it.id == id)
+    }
+
+    pub fn watch(&mut self, id: String, status: TaskStatus) {
+        if !self.contains(&id) {
+            self.0.write().push(WatchedTask { id, status });
+        }
+        spawn(async {
+            request_notification_permission().await;
+        });
+    }
+
+    pub fn unwatch(&mut self, id: &str) {
+        self.0.write().retain(|it| it.id != id);
+    }
+}
+
+pub fn use_watch_list() -> WatchList {
+    use_context::<WatchList>()
+}
+
+async fn request_notification_permission() {
+    if Notification::permission() != NotificationPermission::Default {
+        return;
+    }
+    if let Ok(promise) = Notification::request_permission() {
+        let _ = JsFuture::from(promise).await.inspect_err(|e| tracing::error!("{e:?}"));
+    }
+}
+
+fn notify(task: &WatchedTask) {
+    if Notification::permission() != NotificationPermission::Granted {
+        return;
+    }
+    let options = NotificationOptions::new();
+    options.set_body(&format!("Task {} finished with status {}", task.id, enum_label(&task.status)));
+    options.set_tag(&task.id);
+    let _ = Notification::new_with_options("zkWasm task finished", &options).inspect_err(|e| tracing::error!("{e:?}"));
+}
+
+/// Provides the watch list to every route, restores it from local storage and
+/// polls the active tasks until they reach a terminal status.
+pub fn use_watch_list_provider() -> WatchList {
+    let mut list = use_context_provider(|| WatchList(Signal::new(storage::load(STORAGE_KEY).unwrap_or_default())));
+
+    use_effect(move || storage::save(STORAGE_KEY, &*list.0.read()));
+
+    let zk = use_zk();
+    use_future(move || async move {
+        loop {
+            let active = list.0.peek().iter().filter(|it| it.is_active()).cloned().collect::<Vec<_>>();
+            for watched in active {
+                let Some(task) = zk
+                    .peek()
+                    .query_task_from_id(watched.id.clone())
+                    .await
+                    .inspect_err(|e| tracing::error!("{e}"))
+                    .ok()
+                    .flatten()
+                else {
+                    continue;
+                };
+                if task.status == watched.status {
+                    continue;
+                }
+                let updated = WatchedTask { id: watched.id, status: task.status };
+                if is_terminal(&updated.status) {
+                    notify(&updated);
+                }
+                if let Some(it) = list.0.write().iter_mut().find(|it| it.id == updated.id) {
+                    *it = updated;
+                }
+            }
+            TimeoutFuture::new(POLL_MS).await;
+        }
+    });
+
+    list
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tasks_are_either_polled_or_finished() {
+        for status in [TaskStatus::Pending, TaskStatus::Processing, TaskStatus::DryRunSuccess] {
+            assert!(!is_terminal(&status));
+        }
+        for status in [
+            TaskStatus::DryRunFailed,
+            TaskStatus::Done,
+            TaskStatus::Fail,
+            TaskStatus::Unprovable,
+            TaskStatus::Stale,
+        ] {
+            assert!(is_terminal(&status));
+            assert!(!WatchedTask { id: String::new(), status }.is_active());
+        }
+    }
+}
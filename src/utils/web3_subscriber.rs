@@ -5,34 +5,43 @@ use wasm_bindgen_futures::js_sys;
 use wasm_bindgen_futures::JsFuture;
 use web_sys::window;
 
-use crate::utils::web3_subscriber::js_sys::Promise;
 use crate::utils::shorten_address;
+use crate::utils::web3_subscriber::js_sys::Promise;
 
 #[derive(Clone, PartialEq)]
 pub struct WalletAccount(String);
 
+impl WalletAccount {
+    pub fn address(&self) -> &str {
+        &self.0
+    }
+}
+
 /// When you install MetaMask, it injects a JS object into every webpage "window.ethereum" object.
 /// That object is an EIP-1193 provider with a single generic method "ethereum.request({ method: "eth_requestAccounts" })".
 /// This method takes a JSON-RPC request and returns a Promise. MetaMask listens for those calls and opens the wallet popup.
 /// So if you can call window.ethereum.request, you can connect, get accounts, switch networks, sign messages, etc.
 ///
-pub async fn connect_wallet() -> Result<WalletAccount, JsValue> {
+async fn ethereum_request(method: &str, params: Option<js_sys::Array>) -> Result<JsValue, JsValue> {
     // Get window.ethereum
     let window = window().ok_or("no window")?;
     let ethereum = js_sys::Reflect::get(&window, &JsValue::from_str("ethereum"))?;
 
     // Construct ethereum.request object
     let request_args = js_sys::Object::new();
-    js_sys::Reflect::set(
-        &request_args,
-        &JsValue::from_str("method"),
-        &JsValue::from_str("eth_requestAccounts"),
-    )?;
+    js_sys::Reflect::set(&request_args, &JsValue::from_str("method"), &JsValue::from_str(method))?;
+    if let Some(params) = params {
+        js_sys::Reflect::set(&request_args, &JsValue::from_str("params"), &params)?;
+    }
 
     // Send request
     let request_fn = js_sys::Reflect::get(&ethereum, &JsValue::from_str("request"))?.dyn_into::<js_sys::Function>()?;
     let promise: Promise = request_fn.call1(&ethereum, &request_args)?.into();
-    let result = JsFuture::from(promise).await?;
+    JsFuture::from(promise).await
+}
+
+pub async fn connect_wallet() -> Result<WalletAccount, JsValue> {
+    let result = ethereum_request("eth_requestAccounts", None).await?;
 
     // Map output
     let accounts: js_sys::Array = result.into();
@@ -46,6 +55,60 @@ pub async fn connect_wallet() -> Result<WalletAccount, JsValue> {
     Ok(WalletAccount(account))
 }
 
+/// Signs a plain text message with "personal_sign", MetaMask expects the message hex encoded.
+pub async fn sign_message(account: &WalletAccount, message: &str) -> Result<String, JsValue> {
+    let params = js_sys::Array::of2(
+        &JsValue::from_str(&format!("0x{}", hex::encode(message))),
+        &JsValue::from_str(&account.0),
+    );
+    ethereum_request("personal_sign", Some(params))
+        .await?
+        .as_string()
+        .ok_or("Signature was not a string".into())
+}
+
+fn js_object(entries: &[(&str, &str)]) -> Result<JsValue, JsValue> {
+    let obj = js_sys::Object::new();
+    for (key, value) in entries {
+        js_sys::Reflect::set(&obj, &JsValue::from_str(key), &JsValue::from_str(value))?;
+    }
+    Ok(obj.into())
+}
+
+pub async fn switch_chain(chain_id: u64) -> Result<(), JsValue> {
+    let params = js_sys::Array::of1(&js_object(&[("chainId", &format!("{chain_id:#x}"))])?);
+    ethereum_request("wallet_switchEthereumChain", Some(params)).await.map(|_| ())
+}
+
+/// Sends a transaction from the connected account and returns its hash once MetaMask broadcasts it.
+pub async fn send_transaction(account: &WalletAccount, to: &str, data: &[u8]) -> Result<String, JsValue> {
+    let tx = js_object(&[
+        ("from", &account.0),
+        ("to", to),
+        ("data", &format!("0x{}", hex::encode(data))),
+    ])?;
+    ethereum_request("eth_sendTransaction", Some(js_sys::Array::of1(&tx)))
+        .await?
+        .as_string()
+        .ok_or("Transaction hash was not a string".into())
+}
+
+/// Returns `None` while the transaction is not mined, otherwise whether it succeeded.
+pub async fn transaction_status(hash: &str) -> Result<Option<bool>, JsValue> {
+    let params = js_sys::Array::of1(&JsValue::from_str(hash));
+    let receipt = ethereum_request("eth_getTransactionReceipt", Some(params)).await?;
+    if receipt.is_null() || receipt.is_undefined() {
+        return Ok(None);
+    }
+    let status = js_sys::Reflect::get(&receipt, &JsValue::from_str("status"))?;
+    Ok(Some(status.as_string().as_deref() == Some("0x1")))
+}
+
+/// The connected account is provided by the navbar so any page can check ownership or sign requests.
+pub fn use_wallet() -> Signal<Option<WalletAccount>> {
+    use_context::<Signal<Option<WalletAccount>>>()
+}
+
 #[component]
 pub fn ConnectWallet(account: Signal<Option<WalletAccount>>) -> Element {
     rsx! {
@@ -57,7 +120,7 @@ pub fn ConnectWallet(account: Signal<Option<WalletAccount>>) -> Element {
                 });
             },
             if let Some(acc) = account() {
-                "Connected - {shorten_address(&acc.0)}"
+                "Connected - {shorten_address(&acc.0).unwrap_or(acc.0.clone())}"
             } else {
                 "Connect MetaMask"
             }
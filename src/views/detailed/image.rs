@@ -3,23 +3,30 @@ use zkp_service_helper::interface::AddProveTaskRestrictions;
 use zkp_service_helper::interface::AppConfig;
 use zkp_service_helper::interface::ConciseTask;
 use zkp_service_helper::interface::Image;
+use zkp_service_helper::interface::PaginationResult;
 use zkp_service_helper::interface::ProvePaymentSrc;
 use zkp_service_helper::interface::TaskType;
 
 use crate::components::card::Card;
 use crate::components::card::EntryListCard;
 use crate::components::card::EntryListLike;
-use crate::components::table::Table;
+use crate::components::status::NotFound;
+use crate::components::table::QueryTable;
+use crate::utils::service::use_zk;
+use crate::utils::service::ServiceFuture;
+use crate::utils::service::ZkService;
+use crate::utils::use_query;
+use crate::utils::web3_subscriber::use_wallet;
 use crate::utils::AddressKind;
 use crate::utils::AddressStyle;
 use crate::utils::TimestampStyle;
 use crate::utils::ZkEntry;
+use crate::views::forms::ResetImageForm;
 use crate::GLOBAL_PADDING;
-use crate::ZKH;
 
 #[derive(Clone, PartialEq)]
 struct DetailedImage {
-    submit_time: String,
+    submit_time: Option<String>,
     networks: Vec<String>,
     creator_paid_proof: String,
     only_creator_add: String,
@@ -28,24 +35,15 @@ struct DetailedImage {
 }
 
 impl DetailedImage {
-    fn new(
-        image_in: Signal<Option<Image>>,
-        config_in: Signal<Option<AppConfig>>,
-        task_in: Signal<Option<ConciseTask>>,
-        proofs_submitted_in: Signal<Option<u64>>,
-    ) -> Option<Self> {
-        let image = image_in.read().as_ref().cloned()?;
-        let config = config_in.read().as_ref().cloned()?;
-        let task = task_in.read().as_ref().cloned()?;
-        let proofs_submitted = proofs_submitted_in.read().as_ref().cloned()?;
+    fn new(image: Image, config: AppConfig, setup: Option<ConciseTask>, proofs_submitted: u64) -> Self {
         let networks = config
             .chain_info_list
             .iter()
             .filter(|it| image.auto_submit_network_ids.iter().any(|id| *id == it.chain_id))
             .map(|it| it.chain_name.clone())
             .collect();
-        Some(Self {
-            submit_time: task.submit_time,
+        Self {
+            submit_time: setup.map(|it| it.submit_time),
             networks,
             creator_paid_proof: match image.prove_payment_src {
                 ProvePaymentSrc::Default => "No",
@@ -59,11 +57,11 @@ impl DetailedImage {
             .to_string(),
             proofs_submitted,
             image,
-        })
+        }
     }
 }
 
-impl EntryListLike for Option<DetailedImage> {
+impl EntryListLike for DetailedImage {
     type T = ZkEntry;
 
     fn title(&self) -> String {
@@ -71,111 +69,114 @@ impl EntryListLike for Option<DetailedImage> {
     }
 
     fn entries(&self) -> Vec<(&str, ZkEntry)> {
-        self.as_ref()
-            .map(|it| {
-                vec![
-                    (
-                        "Owner",
-                        ZkEntry::Address(it.image.user_address.clone(), AddressStyle::Detailed, AddressKind::User),
-                    ),
-                    (
-                        "Created On",
-                        ZkEntry::Timestamp(Some(it.submit_time.clone()), TimestampStyle::Simple),
-                    ),
-                    ("Auto Submit Proof Network(s)", ZkEntry::Raw(it.networks.join(" "))),
-                    ("Circuit Size", ZkEntry::Raw(it.image.circuit_size.to_string())),
-                    ("Creator Paid Proof", ZkEntry::Raw(it.creator_paid_proof.clone())),
-                    (
-                        "Only image creator can add prove task",
-                        ZkEntry::Raw(it.only_creator_add.clone()),
-                    ),
-                    ("Proofs submitted", ZkEntry::Raw(it.proofs_submitted.to_string())),
-                    ("Image Commitment", ZkEntry::Checksum(it.image.checksum.clone())),
-                    ("Image Status", ZkEntry::Raw(it.image.status.clone())),
-                    (
-                        "Shared Data Image",
-                        ZkEntry::Raw(
-                            it.image
-                                .inherited_merkle_data_info
-                                .as_ref()
-                                .map(|x| x.md5.clone())
-                                .unwrap_or("NA".to_string()),
-                        ),
-                    ),
-                ]
-            })
-            .unwrap_or_default()
+        let it = self;
+        vec![
+            (
+                "Owner",
+                ZkEntry::Address(it.image.user_address.clone(), AddressStyle::Detailed, AddressKind::User),
+            ),
+            ("Created On", ZkEntry::Timestamp(it.submit_time.clone(), TimestampStyle::Simple)),
+            ("Auto Submit Proof Network(s)", ZkEntry::Raw(it.networks.join(" "))),
+            ("Circuit Size", ZkEntry::Number(it.image.circuit_size.to_string())),
+            ("Creator Paid Proof", ZkEntry::Raw(it.creator_paid_proof.clone())),
+            (
+                "Only image creator can add prove task",
+                ZkEntry::Raw(it.only_creator_add.clone()),
+            ),
+            ("Proofs submitted", ZkEntry::Number(it.proofs_submitted.to_string())),
+            ("Image Commitment", ZkEntry::Checksum(it.image.checksum.clone())),
+            ("Image Status", ZkEntry::Raw(it.image.status.clone())),
+            (
+                "Shared Data Image",
+                ZkEntry::Raw(
+                    it.image
+                        .inherited_merkle_data_info
+                        .as_ref()
+                        .map(|x| x.md5.clone())
+                        .unwrap_or("NA".to_string()),
+                ),
+            ),
+        ]
     }
 }
 
+fn query_image_tasks(
+    zk: &dyn ZkService,
+    md5: String,
+    tasktype: TaskType,
+) -> ServiceFuture<'_, PaginationResult<Vec<ConciseTask>>> {
+    zk.query_concise_tasks(None, Some(md5), None, Some(tasktype), None, None, None)
+}
+
 #[component]
 pub fn ImageDetails(id: String) -> Element {
     tracing::info!("Image detail loading {id}");
 
-    // TODO: make a trait for these to impl like QueryFunctionHandler
-    let md5 = id.clone();
-    let mut config = use_signal(|| Option::<AppConfig>::None);
-    use_future(move || async move {
-        config.set(ZKH.query_config().await.inspect_err(|e| tracing::error!("{e}")).ok());
-    });
-
-    let md5_for_setup = md5.clone();
-    let mut setup = use_signal(|| Option::<ConciseTask>::None);
-    let mut setups = use_signal(Vec::<ConciseTask>::new);
-    use_future(move || {
-        let md5_cp = md5_for_setup.clone();
-        async move {
-            let res = ZKH
-                .query_concise_tasks(None, Some(md5_cp), None, Some(TaskType::Setup), None, None, None)
-                .await
-                .inspect_err(|e| tracing::error!("{e}"))
-                .ok()
-                .map(|res| res.data);
-            setup.set(res.clone().and_then(|res| res.first().cloned()));
-            setups.set(res.unwrap_or(vec![]));
-        }
-    });
-
-    let md5_for_proves = md5.clone();
-    let mut proves = use_signal(Vec::<ConciseTask>::new);
-    let mut prove_count = use_signal(|| Option::<u64>::None);
-    use_future(move || {
-        let md5_cp = md5_for_proves.clone();
-        async move {
-            let res = ZKH
-                .query_concise_tasks(None, Some(md5_cp), None, Some(TaskType::Prove), None, None, None)
-                .await;
-            prove_count.set(res.as_ref().map(|it| it.total).ok());
-            proves.set(res.map(|res| res.data).unwrap_or(vec![]));
-        }
-    });
+    let zk = use_zk();
+    let mut config = use_query(move || zk.run(|zk| zk.query_config()));
+    let mut image = use_query(use_reactive!(|id| zk.run(move |zk| zk.query_image(id))));
+    let mut setups = use_query(use_reactive!(|id| zk.run(move |zk| query_image_tasks(zk, id, TaskType::Setup))));
+    let mut proves = use_query(use_reactive!(|id| zk.run(move |zk| query_image_tasks(zk, id, TaskType::Prove))));
+    let mut resets = use_query(use_reactive!(|id| zk.run(move |zk| query_image_tasks(zk, id, TaskType::Reset))));
 
-    let md5_for_image = md5.clone();
-    let mut image = use_signal(|| Option::<Image>::None);
-    use_future(move || {
-        let md5_cp = md5_for_image.clone();
-        async move {
-            let result = ZKH.query_image(md5_cp).await.unwrap_or_default();
-            image.set(result);
-        }
-    });
+    let account = use_wallet();
+    let mut reset_open = use_signal(|| false);
+    let loaded_image = image.ready().flatten();
+    let is_owner = loaded_image
+        .as_ref()
+        .zip(account.as_ref())
+        .is_some_and(|(img, acc)| img.user_address.eq_ignore_ascii_case(acc.address()));
 
-    let desc = image.as_ref().map(|it| it.description_url.clone()).unwrap_or("NA".to_string());
-    let left = format!("Image Hash {}", md5);
-    let right = image.as_ref().map(|it| it.user_address.clone()).unwrap_or("NA".to_string());
+    let desc = loaded_image
+        .as_ref()
+        .map(|it| it.description_url.clone())
+        .unwrap_or("NA".to_string());
+    let left = format!("Image Hash {id}");
+    let right = loaded_image
+        .as_ref()
+        .map(|it| it.user_address.clone())
+        .unwrap_or("NA".to_string());
+    let overview = image.state().and(config.state()).and(setups.state()).and(proves.state());
+    let retry_overview = move || {
+        image.retry();
+        config.retry();
+        setups.retry();
+        proves.retry();
+    };
     rsx! {
         div { style: GLOBAL_PADDING,
             div { id: "detail-header",
                 div { "{left}" }
-                div { id: "right-div", "{right}" }
+                div { id: "right-div",
+                    "{right}"
+                    if is_owner {
+                        button { class: "action-button", onclick: move |_| reset_open.set(true), "Reset" }
+                    }
+                }
+            }
+        }
+        if let Some(img) = loaded_image {
+            ResetImageForm {
+                open: reset_open,
+                account,
+                image: img,
+                config: config.ready(),
+                onsubmitted: move |_| resets.retry(),
             }
         }
         div { class: "stretched-nested-div-parent",
             div { class: "flex-1 pad-5",
-                EntryListCard {
-                    data: DetailedImage::new(image, config, setup, prove_count),
-                    lcol_class: "image-details-col",
-                }
+                {overview.render(retry_overview, |(((image, config), setups), proves)| match image {
+                    Some(image) => rsx! {
+                        EntryListCard {
+                            data: DetailedImage::new(image, config, setups.data.first().cloned(), proves.total),
+                            lcol_class: "image-details-col",
+                        }
+                    },
+                    None => rsx! {
+                        NotFound { message: "No image with MD5 {id}" }
+                    },
+                })}
             }
             div { class: "pad-5 stretched-nested-div",
                 Card {
@@ -186,7 +187,49 @@ pub fn ImageDetails(id: String) -> Element {
                 }
             }
         }
-        Table { data: proves() }
-        Table { data: setups() }
+        QueryTable { query: proves }
+        QueryTable { query: setups }
+        QueryTable { query: resets }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::fixtures;
+    use crate::testing::minimal;
+    use crate::testing::render;
+    use crate::utils::mock::Fixtures;
+
+    fn render_images(fixtures: Fixtures) -> String {
+        let config = fixtures.config.unwrap_or_default();
+        fixtures
+            .images
+            .into_iter()
+            .map(|image| {
+                let setup = fixtures
+                    .concise_tasks
+                    .iter()
+                    .find(|it| it.md5 == image.md5 && it.task_type == TaskType::Setup)
+                    .cloned();
+                let data = DetailedImage::new(image, config.clone(), setup, 3);
+                render(move || {
+                    rsx! {
+                        EntryListCard { data: data.clone() }
+                    }
+                })
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn image_overview() {
+        insta::assert_snapshot!(render_images(fixtures()));
+    }
+
+    #[test]
+    fn image_overview_with_missing_fields() {
+        insta::assert_snapshot!(render_images(minimal()));
     }
 }
@@ -6,7 +6,12 @@ use zkp_service_helper::interface::ProverNodeTimeRangeStatsParams;
 
 use crate::components::card::EntryListCard;
 use crate::components::card::EntryListLike;
-use crate::utils::enum_to_string;
+use crate::components::status::NotFound;
+use crate::utils::enum_label;
+use crate::utils::service::use_zk;
+use crate::utils::service::ServiceFuture;
+use crate::utils::service::ZkService;
+use crate::utils::use_query;
 use crate::utils::webtime_to_rfc3339;
 use crate::utils::AddressKind;
 use crate::utils::AddressStyle;
@@ -14,11 +19,48 @@ use crate::utils::TimestampStyle;
 use crate::utils::UnwrapOrNA;
 use crate::utils::ZkEntry;
 use crate::GLOBAL_PADDING;
-use crate::ZKH;
+
+const NODE_BATCH: u64 = 100;
+/// Scans of every prover node stop after this many.
+pub(in crate::views) const NODE_SCAN_LIMIT: u64 = 1000;
+
+/// Every node up to `NODE_SCAN_LIMIT`, `truncated` when the service has more.
+pub(in crate::views) struct NodeScan {
+    pub nodes: Vec<ProverNode>,
+    pub truncated: bool,
+}
+
+pub(in crate::views) fn scan_nodes(zk: &dyn ZkService) -> ServiceFuture<'_, NodeScan> {
+    Box::pin(async move {
+        let mut nodes = vec![];
+        let mut truncated = false;
+        while (nodes.len() as u64) < NODE_SCAN_LIMIT {
+            let per = NODE_BATCH.min(NODE_SCAN_LIMIT - nodes.len() as u64);
+            let res = zk.query_node_statistics(None, Some(nodes.len() as u64), Some(per)).await?;
+            if res.data.is_empty() {
+                break;
+            }
+            nodes.extend(res.data);
+            truncated = (nodes.len() as u64) < res.total;
+            if !truncated {
+                break;
+            }
+        }
+        Ok(NodeScan { nodes, truncated })
+    })
+}
+
+/// Whether the node last reported itself online, N/A when it never reported any activity.
+pub(in crate::views) fn online_status(node: &ProverNode) -> String {
+    node.online_activity
+        .as_ref()
+        .map(|it| if it.online { "Online" } else { "Offline" }.to_string())
+        .unwrap_or_na()
+}
 
 #[derive(Clone, PartialEq)]
 struct GeneralNodeDetails {
-    node: Option<ProverNode>,
+    node: ProverNode,
 }
 
 impl EntryListLike for GeneralNodeDetails {
@@ -29,9 +71,7 @@ impl EntryListLike for GeneralNodeDetails {
     }
 
     fn entries(&self) -> Vec<(&str, Self::T)> {
-        let Some(node) = &self.node else {
-            return vec![];
-        };
+        let node = &self.node;
         let stats = &node.statistics;
         vec![
             (
@@ -42,16 +82,8 @@ impl EntryListLike for GeneralNodeDetails {
                 "Latest Node Version Used",
                 ZkEntry::Raw(node.version_info.as_ref().map(|it| it.version.clone()).unwrap_or_na()),
             ),
-            ("Prover Level", ZkEntry::Raw(enum_to_string(&node.prover_level))),
-            (
-                "Status",
-                ZkEntry::Raw(
-                    node.online_activity
-                        .as_ref()
-                        .map(|it| if it.online { "Online" } else { "Offline" }.to_string())
-                        .unwrap_or_na(),
-                ),
-            ),
+            ("Prover Level", ZkEntry::Raw(enum_label(&node.prover_level))),
+            ("Status", ZkEntry::Raw(online_status(node))),
             ("Performance Track", ZkEntry::Raw(node.performance_track.clone())),
             (
                 "Last Attempted Task",
@@ -68,10 +100,10 @@ impl EntryListLike for GeneralNodeDetails {
                     TimestampStyle::Full,
                 ),
             ),
-            ("Total Tasks Taken", ZkEntry::Raw(stats.total_tasks.to_string())),
+            ("Total Tasks Taken", ZkEntry::Number(stats.total_tasks.to_string())),
             (
                 "Total Successful Tasks Completed ",
-                ZkEntry::Raw(stats.successful_tasks.to_string()),
+                ZkEntry::Number(stats.successful_tasks.to_string()),
             ),
         ]
     }
@@ -79,7 +111,7 @@ impl EntryListLike for GeneralNodeDetails {
 
 #[derive(Clone, PartialEq)]
 struct FailureNodeDetails {
-    node: Option<ProverNode>,
+    node: ProverNode,
 }
 
 impl EntryListLike for FailureNodeDetails {
@@ -90,12 +122,10 @@ impl EntryListLike for FailureNodeDetails {
     }
 
     fn entries(&self) -> Vec<(&str, Self::T)> {
-        let Some(stats) = self.node.as_ref().map(|it| &it.statistics) else {
-            return vec![];
-        };
+        let stats = &self.node.statistics;
         vec![
-            ("Failed Tasks", ZkEntry::Raw(stats.failed_tasks.to_string())),
-            ("Timed Out Tasks", ZkEntry::Raw(stats.timed_out_count.to_string())),
+            ("Failed Tasks", ZkEntry::Number(stats.failed_tasks.to_string())),
+            ("Timed Out Tasks", ZkEntry::Number(stats.timed_out_count.to_string())),
             (
                 "Last Timed Out Timestamp",
                 ZkEntry::Timestamp(stats.last_timed_out.clone(), TimestampStyle::Full),
@@ -127,7 +157,7 @@ impl EntryListLike for FailureNodeDetails {
 
 #[derive(Clone, PartialEq)]
 struct SuccessfulSetupNodeStats {
-    node: Option<ProverNode>,
+    node: ProverNode,
 }
 
 impl EntryListLike for SuccessfulSetupNodeStats {
@@ -138,7 +168,7 @@ impl EntryListLike for SuccessfulSetupNodeStats {
     }
 
     fn entries(&self) -> Vec<(&str, Self::T)> {
-        let stats = self.node.as_ref().and_then(|it| it.statistics.setup_timing_stats.as_ref());
+        let stats = self.node.statistics.setup_timing_stats.as_ref();
         vec![
             (
                 "Last Successful Task",
@@ -166,7 +196,7 @@ impl EntryListLike for SuccessfulSetupNodeStats {
 
 #[derive(Clone, PartialEq)]
 struct SuccessfulProveNodeStats {
-    node: Option<ProverNode>,
+    node: ProverNode,
 }
 
 impl EntryListLike for SuccessfulProveNodeStats {
@@ -177,7 +207,7 @@ impl EntryListLike for SuccessfulProveNodeStats {
     }
 
     fn entries(&self) -> Vec<(&str, Self::T)> {
-        let stats = self.node.as_ref().and_then(|it| it.statistics.proof_timing_stats.as_ref());
+        let stats = self.node.statistics.proof_timing_stats.as_ref();
         vec![
             (
                 "Last Successful Task",
@@ -220,9 +250,9 @@ impl EntryListLike for LastMonthsNodeStats {
             return vec![];
         };
         vec![
-            ("Successful Tasks Number", ZkEntry::Raw(stats.stats.successful.to_string())),
-            ("Failed Tasks Number", ZkEntry::Raw(stats.stats.failed.to_string())),
-            ("Timed Out Tasks Number", ZkEntry::Raw(stats.stats.timed_out.to_string())),
+            ("Successful Tasks Number", ZkEntry::Number(stats.stats.successful.to_string())),
+            ("Failed Tasks Number", ZkEntry::Number(stats.stats.failed.to_string())),
+            ("Timed Out Tasks Number", ZkEntry::Number(stats.stats.timed_out.to_string())),
         ]
     }
 }
@@ -244,76 +274,94 @@ fn make_node_details_div<U: EntryListLike + PartialEq + Clone + 'static>(data: U
 pub fn NodeDetails(id: String) -> Element {
     tracing::info!("Node detail loading {id}");
 
-    // TODO: make trait like QueryFunctionHandler
-    let mut node = use_signal(|| Option::<ProverNode>::None);
-    let id_for_node = id.clone();
-    use_future(move || {
-        let address = id_for_node.clone();
+    let zk = use_zk();
+    let node = use_query(use_reactive!(|id| {
+        let zk = zk.get();
         async move {
-            node.set(
-                ZKH.query_node_statistics(Some(address), None, Some(1))
-                    .await
-                    .inspect_err(|e| tracing::error!("{e}"))
-                    .ok()
-                    .and_then(|res| res.data.first().cloned()),
-            );
+            zk.query_node_statistics(Some(id), None, Some(1))
+                .await
+                .map(|res| res.data.first().cloned())
         }
-    });
-
-    let id_for_stats = id.clone();
-    let mut stats = use_signal(|| Option::<ProverNodeTimeRangeStats>::None);
-    use_future(move || {
-        let address = id_for_stats.clone();
-        let now = web_time::SystemTime::now();
-        let then = now - web_time::Duration::from_secs(4 * 7 * 24 * 60 * 60);
+    }));
+    let stats = use_query(use_reactive!(|id| {
+        let zk = zk.get();
         async move {
-            stats.set(
-                ZKH.query_prover_node_timerange_stats(ProverNodeTimeRangeStatsParams {
-                    ranges: vec![ProverNodeTimeRange {
-                        address,
-                        start: webtime_to_rfc3339(then),
-                        end: webtime_to_rfc3339(now),
-                    }],
-                })
-                .await
-                .inspect_err(|e| tracing::error!("{e}"))
-                .ok()
-                .and_then(|it| it.first().cloned()),
-            );
+            let now = web_time::SystemTime::now();
+            let then = now - web_time::Duration::from_secs(4 * 7 * 24 * 60 * 60);
+            zk.query_prover_node_timerange_stats(ProverNodeTimeRangeStatsParams {
+                ranges: vec![ProverNodeTimeRange {
+                    address: id,
+                    start: webtime_to_rfc3339(then),
+                    end: webtime_to_rfc3339(now),
+                }],
+            })
+            .await
+            .map(|it| it.first().cloned())
         }
-    });
+    }));
 
-    let node_details = node();
     rsx! {
         div { style: GLOBAL_PADDING,
             div { id: "detail-header",
                 div { "{id}" }
             }
         }
-        {
-            make_node_details_div(GeneralNodeDetails {
-                node: node_details.clone(),
-            })
-        }
-        {
-            make_node_details_div(FailureNodeDetails {
-                node: node_details.clone(),
-            })
-        }
-        {
-            make_node_details_div(SuccessfulSetupNodeStats {
-                node: node_details.clone(),
-            })
-        }
-        {
-            make_node_details_div(SuccessfulProveNodeStats {
-                node: node_details.clone(),
-            })
-        }
-        {
-            make_node_details_div(LastMonthsNodeStats {
-                timerange_stats: stats(),
+        {node.render(|node| match node {
+            Some(node) => rsx! {
+                {make_node_details_div(GeneralNodeDetails { node: node.clone() })}
+                {make_node_details_div(FailureNodeDetails { node: node.clone() })}
+                {make_node_details_div(SuccessfulSetupNodeStats { node: node.clone() })}
+                {make_node_details_div(SuccessfulProveNodeStats { node })}
+            },
+            None => rsx! {
+                div { style: GLOBAL_PADDING,
+                    NotFound { message: "No prover node with address {id}" }
+                }
+            },
+        })}
+        {stats.render(|timerange_stats| make_node_details_div(LastMonthsNodeStats { timerange_stats }))}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::fixtures;
+    use crate::testing::minimal;
+    use crate::testing::render;
+    use crate::utils::mock::Fixtures;
+
+    fn render_nodes(fixtures: Fixtures) -> String {
+        fixtures
+            .nodes
+            .into_iter()
+            .map(|node| {
+                let timerange_stats = fixtures
+                    .node_timerange_stats
+                    .iter()
+                    .find(|it| it.address == node.address)
+                    .cloned();
+                render(move || {
+                    rsx! {
+                        {make_node_details_div(GeneralNodeDetails { node: node.clone() })}
+                        {make_node_details_div(FailureNodeDetails { node: node.clone() })}
+                        {make_node_details_div(SuccessfulSetupNodeStats { node: node.clone() })}
+                        {make_node_details_div(SuccessfulProveNodeStats { node: node.clone() })}
+                        {make_node_details_div(LastMonthsNodeStats { timerange_stats: timerange_stats.clone() })}
+                    }
+                })
             })
-        }
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn node_details() {
+        insta::assert_snapshot!(render_nodes(fixtures()));
+    }
+
+    #[test]
+    fn node_details_with_missing_fields() {
+        insta::assert_snapshot!(render_nodes(minimal()));
     }
 }
@@ -1,21 +1,30 @@
 use dioxus::prelude::*;
 use zkp_service_helper::interface::ProofSubmitMode;
 use zkp_service_helper::interface::Task;
+use zkp_service_helper::interface::TaskStatus;
 
 use crate::components::card::EntryListCard;
 use crate::components::card::EntryListLike;
+use crate::components::status::NotFound;
 use crate::utils::bytes_to_num_string;
 use crate::utils::calc_processing_time_secs;
-use crate::utils::enum_to_string;
+use crate::utils::download::download_bytes;
+use crate::utils::enum_label;
+use crate::utils::preferences::preferences;
+use crate::utils::proof::ProofBundle;
+use crate::utils::service::use_zk;
+use crate::utils::use_query;
+use crate::utils::web3_subscriber::use_wallet;
 use crate::utils::AddressKind;
 use crate::utils::AddressStyle;
 use crate::utils::TimestampStyle;
 use crate::utils::UnwrapOrNA;
 use crate::utils::ZkEntry;
+use crate::views::forms::SubmitProofForm;
+use crate::views::watch::WatchButton;
 use crate::GLOBAL_PADDING;
-use crate::ZKH;
 
-impl EntryListLike for Option<Task> {
+impl EntryListLike for Task {
     type T = ZkEntry;
 
     fn title(&self) -> String {
@@ -23,76 +32,165 @@ impl EntryListLike for Option<Task> {
     }
 
     fn entries(&self) -> Vec<(&str, ZkEntry)> {
-        self.as_ref()
-            .map(|it| {
-                vec![
-                    (
-                        "Application",
-                        ZkEntry::Address(it.md5.clone(), AddressStyle::Detailed, AddressKind::Image),
-                    ),
-                    ("Type", ZkEntry::TaskType(it.task_type.clone())),
-                    ("Status", ZkEntry::TaskStatus(it.status.clone())),
-                    (
-                        "Submitted at",
-                        ZkEntry::Timestamp(Some(it.submit_time.clone()), TimestampStyle::Full),
-                    ),
-                    (
-                        "Submitted by",
-                        ZkEntry::Address(it.user_address.clone(), AddressStyle::Detailed, AddressKind::User),
-                    ),
-                    (
-                        "Task taken by Node",
-                        ZkEntry::MaybeAddress(it.node_address.clone(), AddressStyle::Detailed, AddressKind::Node),
-                    ),
-                    (
-                        "Processing Started",
-                        ZkEntry::Timestamp(it.process_started.clone(), TimestampStyle::Full),
-                    ),
-                    (
-                        "Processing Finished",
-                        ZkEntry::Timestamp(it.process_finished.clone(), TimestampStyle::Full),
-                    ),
-                    (
-                        "Processing Time",
-                        ZkEntry::Raw(
-                            calc_processing_time_secs(it.process_started.clone(), it.process_finished.clone())
-                                .map(|dur| format!("{dur} seconds"))
-                                .unwrap_or("NA".to_string()),
-                        ),
-                    ),
-                    (
-                        "Task Fee",
-                        ZkEntry::Raw(bytes_to_num_string(it.task_fee.clone()).unwrap_or_na()),
-                    ),
-                    ("Debug Logs", ZkEntry::Logs(it.debug_logs.clone())),
-                    (
-                        "Guest Statics",
-                        ZkEntry::Raw(it.guest_statics.map(|x| x.to_string()).unwrap_or_na()),
-                    ),
-                    (
-                        "Proof Submit Mode",
-                        ZkEntry::Raw(enum_to_string(
-                            it.proof_submit_mode.as_ref().unwrap_or(&ProofSubmitMode::Manual),
-                        )),
-                    ),
-                    (
-                        "Current Batch Status",
-                        ZkEntry::Raw(it.auto_submit_status.as_ref().map(enum_to_string).unwrap_or_na()),
-                    ),
-                    ("Public Inputs", ZkEntry::LongInput(it.public_inputs.clone())),
-                    ("Witness", ZkEntry::LongInput(it.private_inputs.clone())),
-                    ("External Host Table", ZkEntry::DownloadButton(it._id.oid.clone())),
-                    ("Input Context", ZkEntry::Bytes(it.input_context.clone(), Some(8))),
-                    ("Context Output", ZkEntry::Bytes(it.output_context.clone(), Some(8))),
-                    ("Single Proof Transcripts", ZkEntry::Bytes(it.single_proof.clone(), None)),
-                    ("Instances", ZkEntry::Bytes(it.instances.clone(), None)),
-                    ("Batched Proof Transcripts", ZkEntry::Bytes(it.proof.clone(), None)),
-                    ("Shadow Instances", ZkEntry::Bytes(it.shadow_instances.clone(), None)),
-                    ("Batch Instances", ZkEntry::Bytes(it.batch_instances.clone(), None)),
-                    ("Aux Data", ZkEntry::Bytes(it.aux.clone(), None)),
-                ]
-            })
-            .unwrap_or_default()
+        let it = self;
+        vec![
+            (
+                "Application",
+                ZkEntry::Address(it.md5.clone(), AddressStyle::Detailed, AddressKind::Image),
+            ),
+            ("Type", ZkEntry::TaskType(it.task_type.clone())),
+            ("Status", ZkEntry::TaskStatus(it.status.clone())),
+            (
+                "Submitted at",
+                ZkEntry::Timestamp(Some(it.submit_time.clone()), TimestampStyle::Full),
+            ),
+            (
+                "Submitted by",
+                ZkEntry::Address(it.user_address.clone(), AddressStyle::Detailed, AddressKind::User),
+            ),
+            (
+                "Task taken by Node",
+                ZkEntry::MaybeAddress(it.node_address.clone(), AddressStyle::Detailed, AddressKind::Node),
+            ),
+            (
+                "Processing Started",
+                ZkEntry::Timestamp(it.process_started.clone(), TimestampStyle::Full),
+            ),
+            (
+                "Processing Finished",
+                ZkEntry::Timestamp(it.process_finished.clone(), TimestampStyle::Full),
+            ),
+            (
+                "Processing Time",
+                ZkEntry::Raw(
+                    calc_processing_time_secs(it.process_started.clone(), it.process_finished.clone())
+                        .map(|dur| format!("{dur} seconds"))
+                        .unwrap_or("NA".to_string()),
+                ),
+            ),
+            (
+                "Task Fee",
+                bytes_to_num_string(it.task_fee.clone())
+                    .map(ZkEntry::Number)
+                    .unwrap_or_default(),
+            ),
+            ("Debug Logs", ZkEntry::Logs(it.debug_logs.clone())),
+            (
+                "Guest Statics",
+                ZkEntry::Raw(it.guest_statics.map(|x| x.to_string()).unwrap_or_na()),
+            ),
+            (
+                "Proof Submit Mode",
+                ZkEntry::Raw(enum_label(it.proof_submit_mode.as_ref().unwrap_or(&ProofSubmitMode::Manual))),
+            ),
+            (
+                "Current Batch Status",
+                ZkEntry::Raw(it.auto_submit_status.as_ref().map(enum_label).unwrap_or_na()),
+            ),
+            ("Public Inputs", ZkEntry::LongInput(it.public_inputs.clone())),
+            (
+                "Witness",
+                if preferences().hide_private_inputs {
+                    ZkEntry::Raw("Hidden, shown again from Settings".to_string())
+                } else {
+                    ZkEntry::LongInput(it.private_inputs.clone())
+                },
+            ),
+            ("External Host Table", ZkEntry::DownloadButton(it._id.oid.clone())),
+            ("Input Context", ZkEntry::Bytes(it.input_context.clone(), Some(8))),
+            ("Context Output", ZkEntry::Bytes(it.output_context.clone(), Some(8))),
+            ("Single Proof Transcripts", ZkEntry::Bytes(it.single_proof.clone(), None)),
+            ("Instances", ZkEntry::Bytes(it.instances.clone(), None)),
+            ("Batched Proof Transcripts", ZkEntry::Bytes(it.proof.clone(), None)),
+            ("Shadow Instances", ZkEntry::Bytes(it.shadow_instances.clone(), None)),
+            ("Batch Instances", ZkEntry::Bytes(it.batch_instances.clone(), None)),
+            ("Aux Data", ZkEntry::Bytes(it.aux.clone(), None)),
+        ]
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum ExportFormat {
+    Json,
+    Solidity,
+    /// One field of the bundle, browsers block all but the first of several downloads from a single click.
+    Binary(&'static str),
+}
+
+impl ExportFormat {
+    fn label(&self) -> String {
+        match self {
+            Self::Json => "JSON bundle".to_string(),
+            Self::Solidity => "Solidity uint256[] arrays".to_string(),
+            Self::Binary(name) => format!("{name}.bin"),
+        }
+    }
+
+    fn export(&self, bundle: &ProofBundle) -> anyhow::Result<()> {
+        let id = &bundle.task_id;
+        let save = |name: String, bytes: &[u8], mime: &str| {
+            download_bytes(&name, bytes, mime).map_err(|e| anyhow::anyhow!("Failed to save {name}: {e:?}"))
+        };
+        match self {
+            Self::Json => save(format!("{id}_proof.json"), bundle.to_json()?.as_bytes(), "application/json"),
+            Self::Solidity => save(format!("{id}_calldata.txt"), bundle.to_solidity().as_bytes(), "text/plain"),
+            Self::Binary(field) => {
+                let (name, bytes) = bundle
+                    .fields
+                    .iter()
+                    .find(|(name, _)| name == field)
+                    .ok_or_else(|| anyhow::anyhow!("No {field} in the proof bundle"))?;
+                save(format!("{id}_{name}.bin"), bytes, "application/octet-stream")
+            }
+        }
+    }
+}
+
+#[component]
+fn ProofExportMenu(bundle: ProofBundle) -> Element {
+    let mut open = use_signal(|| false);
+    let mut error = use_signal(|| Option::<String>::None);
+
+    let binary = bundle
+        .fields
+        .iter()
+        .filter(|(_, bytes)| !bytes.is_empty())
+        .map(|(name, _)| ExportFormat::Binary(name))
+        .collect::<Vec<_>>();
+    // Single files close the menu, the raw fields each need their own click so it stays open for the next one
+    let button = move |format: ExportFormat| {
+        let bundle = bundle.clone();
+        rsx! {
+            button {
+                key: "{format.label()}",
+                onclick: move |_| {
+                    if !matches!(format, ExportFormat::Binary(_)) {
+                        open.set(false);
+                    }
+                    let res = format.export(&bundle).inspect_err(|e| tracing::error!("{e}"));
+                    error.set(res.err().map(|e| e.to_string()));
+                },
+                {format.label()}
+            }
+        }
+    };
+
+    rsx! {
+        div { class: "export-menu",
+            button { class: "action-button", onclick: move |_| open.toggle(), "Export" }
+            if open() {
+                div { class: "export-menu-items",
+                    {[ExportFormat::Json, ExportFormat::Solidity].into_iter().map(button.clone())}
+                    if !binary.is_empty() {
+                        div { class: "export-menu-heading", "Raw binary files" }
+                        {binary.into_iter().map(button)}
+                    }
+                }
+            }
+            if let Some(e) = error() {
+                div { class: "download-error", "{e}" }
+            }
+        }
     }
 }
 
@@ -100,19 +198,18 @@ impl EntryListLike for Option<Task> {
 pub fn TaskDetails(id: String) -> Element {
     tracing::info!("Task detail loading {id}");
 
-    let mut task = use_signal(|| Option::<Task>::None);
-    use_future(move || {
-        let task_id = id.clone();
-        async move {
-            let result = ZKH.query_task_from_id(task_id).await.unwrap_or_default();
-            task.set(result);
-        }
+    let zk = use_zk();
+    let query = use_query(use_reactive!(|id| zk.run(move |zk| zk.query_task_from_id(id))));
+    let task = move || query.ready().flatten();
+
+    let account = use_wallet();
+    let mut submit_open = use_signal(|| false);
+    let manual_done = task().is_some_and(|t| {
+        t.status == TaskStatus::Done
+            && t.proof_submit_mode.unwrap_or(ProofSubmitMode::Manual) == ProofSubmitMode::Manual
     });
 
-    let left = format!(
-        "Task ID {}",
-        task().as_ref().map(|task| task._id.oid.clone()).unwrap_or("NA".to_string()),
-    );
+    let left = format!("Task ID {id}");
     let right = task()
         .as_ref()
         .and_then(|task| task.node_address.clone())
@@ -121,9 +218,55 @@ pub fn TaskDetails(id: String) -> Element {
         div { style: GLOBAL_PADDING,
             div { id: "detail-header",
                 div { "{left}" }
-                div { id: "right-div", "{right}" }
+                div { id: "right-div",
+                    "{right}"
+                    if let Some(t) = task() {
+                        WatchButton { id: t._id.oid.clone(), status: t.status.clone() }
+                        ProofExportMenu { bundle: ProofBundle::new(&t) }
+                    }
+                    if manual_done {
+                        button { class: "action-button", onclick: move |_| submit_open.set(true), "Submit Proof" }
+                    }
+                }
             }
         }
-        EntryListCard { data: task(), lcol_class: "task-details-col" }
+        if let Some(t) = task().filter(|_| manual_done) {
+            SubmitProofForm { open: submit_open, account, bundle: ProofBundle::new(&t) }
+        }
+        {query.render(|task| match task {
+            Some(data) => rsx! {
+                EntryListCard { data, lcol_class: "task-details-col" }
+            },
+            None => rsx! {
+                NotFound { message: "No task with ID {id}" }
+            },
+        })}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::fixtures;
+    use crate::testing::minimal;
+    use crate::testing::render;
+
+    fn render_task(task: Task) -> String {
+        render(move || {
+            rsx! {
+                EntryListCard { data: task.clone() }
+            }
+        })
+    }
+
+    #[test]
+    fn task_overview() {
+        let tasks = fixtures().tasks.into_iter().map(render_task).collect::<Vec<_>>();
+        insta::assert_snapshot!(tasks.join("\n"));
+    }
+
+    #[test]
+    fn task_overview_with_missing_fields() {
+        insta::assert_snapshot!(render_task(minimal().tasks.remove(0)));
     }
 }
@@ -1,25 +1,25 @@
 use dioxus::prelude::*;
-use zkp_service_helper::interface::ConciseTask;
 use zkp_service_helper::interface::Subscription;
 use zkp_service_helper::interface::User;
 
 use crate::components::card::EntryListCard;
 use crate::components::card::EntryListLike;
-use crate::components::table::Table;
-use crate::utils::enum_to_string;
+use crate::components::status::NotFound;
+use crate::components::table::QueryTable;
+use crate::utils::enum_label;
 use crate::utils::hex_to_num_string;
+use crate::utils::service::use_zk;
+use crate::utils::use_query;
 use crate::utils::AddressKind;
 use crate::utils::AddressStyle;
-use crate::utils::UnwrapOrNA;
 use crate::utils::ZkEntry;
 use crate::GLOBAL_PADDING;
-use crate::ZKH;
 
 #[derive(Clone, PartialEq)]
 struct DetailedUser {
-    user: Option<User>,
+    user: User,
     subscription: Option<Subscription>,
-    tasks_submitted: Option<u64>,
+    tasks_submitted: u64,
 }
 
 impl EntryListLike for DetailedUser {
@@ -30,35 +30,26 @@ impl EntryListLike for DetailedUser {
     }
 
     fn entries(&self) -> Vec<(&str, Self::T)> {
-        let Some(user) = &self.user else {
-            return vec![];
-        };
+        let user = &self.user;
         vec![
             (
                 "Address",
                 ZkEntry::Address(user.user_address.clone(), AddressStyle::Detailed, AddressKind::User),
             ),
             (
-                "Balance",
-                ZkEntry::Raw(
-                    hex_to_num_string(&user.credits)
-                        .map(|it| format!("{it} credits"))
-                        .unwrap_or_na(),
-                ),
+                "Balance (credits)",
+                hex_to_num_string(&user.credits).map(ZkEntry::Number).unwrap_or_default(),
             ),
             (
                 "Current Subscription",
                 ZkEntry::Raw(
                     self.subscription
                         .as_ref()
-                        .map(|it| enum_to_string(&it.status))
+                        .map(|it| enum_label(&it.status))
                         .unwrap_or("None".to_string()),
                 ),
             ),
-            (
-                "Total Tasks Submitted",
-                ZkEntry::Raw(self.tasks_submitted.map(|it| it.to_string()).unwrap_or_na()),
-            ),
+            ("Total Tasks Submitted", ZkEntry::Number(self.tasks_submitted.to_string())),
         ]
     }
 }
@@ -67,49 +58,25 @@ impl EntryListLike for DetailedUser {
 pub fn UserDetails(id: String) -> Element {
     tracing::info!("User detail loading {id}");
 
-    // TODO: make trait like QueryFunctionHandler
-    let id_for_user = id.clone();
-    let mut user = use_signal(|| Option::<User>::None);
-    use_future(move || {
-        let address = id_for_user.clone();
-        async move {
-            user.set(
-                ZKH.query_user(address)
-                    .await
-                    .inspect_err(|e| tracing::error!("{e}"))
-                    .ok()
-                    .flatten(),
-            );
-        }
-    });
-
-    let id_for_sub = id.clone();
-    let mut sub = use_signal(|| Option::<Subscription>::None);
-    use_future(move || {
-        let address = id_for_sub.clone();
-        async move {
-            sub.set(
-                ZKH.query_user_subscription(address)
-                    .await
-                    .inspect_err(|e| tracing::error!("{e}"))
-                    .ok()
-                    .flatten(),
-            );
-        }
-    });
-
-    let id_for_tasks = id.clone();
-    let mut tasks = use_signal(Vec::<ConciseTask>::new);
-    let mut tasks_submitted = use_signal(|| Option::<u64>::None);
-    use_future(move || {
-        let address = id_for_tasks.clone();
-        async move {
-            let res = ZKH.query_concise_tasks(Some(address), None, None, None, None, None, None).await;
-            tasks_submitted.set(res.as_ref().map(|res| res.total).ok());
-            tasks.set(res.map(|res| res.data).unwrap_or(vec![]));
-        }
-    });
+    let zk = use_zk();
+    let mut user = use_query(use_reactive!(|id| zk.run(move |zk| zk.query_user(id))));
+    let mut sub = use_query(use_reactive!(|id| zk.run(move |zk| zk.query_user_subscription(id))));
+    let mut tasks = use_query(use_reactive!(|id| zk.run(move |zk| zk.query_concise_tasks(
+        Some(id),
+        None,
+        None,
+        None,
+        None,
+        None,
+        None
+    ))));
 
+    let details = user.state().and(sub.state()).and(tasks.state());
+    let retry_details = move || {
+        user.retry();
+        sub.retry();
+        tasks.retry();
+    };
     rsx! {
         div { style: GLOBAL_PADDING,
             div { id: "detail-header",
@@ -118,17 +85,63 @@ pub fn UserDetails(id: String) -> Element {
             }
         }
         div { class: "node-details-wrapper",
-            EntryListCard {
-                data: DetailedUser {
-                    user: user(),
-                    subscription: sub(),
-                    tasks_submitted: tasks_submitted(),
+            {details.render(retry_details, |((user, subscription), tasks)| match user {
+                Some(user) => rsx! {
+                    EntryListCard {
+                        data: DetailedUser {
+                            user,
+                            subscription,
+                            tasks_submitted: tasks.total,
+                        },
+                        card_class: "transparent-border",
+                        header_class: "node-details-header",
+                        lcol_class: "node-details-col",
+                    }
                 },
-                card_class: "transparent-border",
-                header_class: "node-details-header",
-                lcol_class: "node-details-col",
-            }
+                None => rsx! {
+                    NotFound { message: "No user with address {id}" }
+                },
+            })}
         }
-        Table { data: tasks() }
+        QueryTable { query: tasks }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::fixtures;
+    use crate::testing::minimal;
+    use crate::testing::render;
+    use crate::utils::mock::Fixtures;
+
+    fn render_users(fixtures: Fixtures) -> String {
+        fixtures
+            .users
+            .into_iter()
+            .map(|user| {
+                let data = DetailedUser {
+                    subscription: fixtures.subscriptions.get(&user.user_address.to_lowercase()).cloned(),
+                    user,
+                    tasks_submitted: 5,
+                };
+                render(move || {
+                    rsx! {
+                        EntryListCard { data: data.clone() }
+                    }
+                })
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn user_details() {
+        insta::assert_snapshot!(render_users(fixtures()));
+    }
+
+    #[test]
+    fn user_details_with_missing_fields() {
+        insta::assert_snapshot!(render_users(minimal()));
     }
 }
@@ -0,0 +1,29 @@
+use dioxus::prelude::*;
+
+use crate::utils::environment::use_environments;
+
+#[component]
+pub fn EnvironmentSelect() -> Element {
+    let mut envs = use_environments();
+    let current = envs.current();
+    let config = envs.config.read().clone();
+
+    rsx! {
+        select {
+            id: "environment-select",
+            title: "{current.url}",
+            value: "{current.name}",
+            onchange: move |evt| envs.select(evt.value()),
+            {
+                config
+                    .environments
+                    .into_iter()
+                    .map(|it| {
+                        rsx! {
+                            option { key: "{it.name}", value: "{it.name}", "{it.name}" }
+                        }
+                    })
+            }
+        }
+    }
+}
@@ -0,0 +1,215 @@
+use dioxus::prelude::*;
+use zkp_service_helper::interface::AddImageParams;
+use zkp_service_helper::interface::AddProveTaskRestrictions;
+use zkp_service_helper::interface::AppConfig;
+use zkp_service_helper::interface::ProvePaymentSrc;
+use zkp_service_helper::interface::WithSignature;
+use zkp_service_helper::util::ZkWasmUtil;
+
+use crate::components::modal::Modal;
+use crate::utils::enum_from_string;
+use crate::utils::enum_label;
+use crate::utils::service::use_zk;
+use crate::utils::service::ZkService;
+use crate::utils::use_query;
+use crate::utils::web3_subscriber::sign_message;
+use crate::utils::web3_subscriber::WalletAccount;
+use crate::Route;
+
+pub const CIRCUIT_SIZES: [u32; 7] = [18, 19, 20, 21, 22, 23, 24];
+pub const DEFAULT_CIRCUIT_SIZE: u32 = 22;
+
+#[derive(Clone, PartialEq)]
+struct WasmFile {
+    name: String,
+    bytes: Vec<u8>,
+    md5: String,
+}
+
+async fn submit_image(zk: &dyn ZkService, account: WalletAccount, params: AddImageParams) -> anyhow::Result<String> {
+    let message = ZkWasmUtil::create_add_image_sign_message(&params);
+    let signature = sign_message(&account, &message)
+        .await
+        .map_err(|e| anyhow::anyhow!("Signing failed: {e:?}"))?;
+    let md5 = params.image_md5.clone();
+    zk.add_new_wasm_image(WithSignature { params, signature }).await?;
+    Ok(md5)
+}
+
+#[component]
+pub fn NetworkCheckboxes(config: Option<AppConfig>, selected: Signal<Vec<u64>>) -> Element {
+    let chains = config.map(|it| it.chain_info_list).unwrap_or_default();
+    rsx! {
+        label { "Auto Submit Network(s)" }
+        if chains.is_empty() {
+            small { "No auto submit networks available" }
+        }
+        {
+            chains
+                .into_iter()
+                .map(|chain| {
+                    let id = chain.chain_id;
+                    rsx! {
+                        label { key: "{id}",
+                            input {
+                                r#type: "checkbox",
+                                checked: selected().contains(&id),
+                                onchange: move |evt| {
+                                    if evt.checked() {
+                                        selected.write().push(id);
+                                    } else {
+                                        selected.write().retain(|it| *it != id);
+                                    }
+                                },
+                            }
+                            "{chain.chain_name}"
+                        }
+                    }
+                })
+        }
+    }
+}
+
+#[component]
+pub fn AddImageForm(open: Signal<bool>, account: Signal<Option<WalletAccount>>) -> Element {
+    let zk = use_zk();
+    let config = use_query(move || zk.run(|zk| zk.query_config()));
+
+    let mut file = use_signal(|| Option::<WasmFile>::None);
+    let mut circuit_size = use_signal(|| DEFAULT_CIRCUIT_SIZE);
+    let mut description_url = use_signal(String::new);
+    let networks = use_signal(Vec::<u64>::new);
+    let mut payment = use_signal(|| ProvePaymentSrc::Default);
+    let mut restrictions = use_signal(|| AddProveTaskRestrictions::Anyone);
+    let mut error = use_signal(|| Option::<String>::None);
+    let mut submitting = use_signal(|| false);
+
+    let submit = move |_| async move {
+        let Some(acc) = account() else {
+            error.set(Some("Connect MetaMask before submitting".to_string()));
+            return;
+        };
+        let Some(wasm) = file() else {
+            error.set(Some("Select a .wasm file".to_string()));
+            return;
+        };
+        let params = AddImageParams {
+            name: wasm.name,
+            image_md5: wasm.md5,
+            image: wasm.bytes,
+            user_address: acc.address().to_string(),
+            description_url: description_url(),
+            avator_url: String::new(),
+            circuit_size: circuit_size(),
+            auto_submit_network_ids: networks(),
+            prove_payment_src: payment(),
+            add_prove_task_restrictions: restrictions(),
+        };
+        submitting.set(true);
+        error.set(None);
+        match submit_image(&*zk.peek(), acc, params).await {
+            Ok(md5) => {
+                open.set(false);
+                file.set(None);
+                navigator().push(Route::ImageDetails { id: md5 });
+            }
+            Err(e) => {
+                tracing::error!("{e}");
+                error.set(Some(e.to_string()));
+            }
+        }
+        submitting.set(false);
+    };
+
+    rsx! {
+        Modal {
+            title: "Create New Application",
+            open,
+            footer: rsx! {
+                button {
+                    class: "confirm",
+                    disabled: submitting() || file().is_none(),
+                    onclick: submit,
+                    if submitting() {
+                        "Submitting..."
+                    } else {
+                        "Submit"
+                    }
+                }
+            },
+            label { "Image (.wasm)" }
+            input {
+                r#type: "file",
+                accept: ".wasm",
+                onchange: move |evt| async move {
+                    let Some(engine) = evt.files() else {
+                        return;
+                    };
+                    let Some(name) = engine.files().first().cloned() else {
+                        return;
+                    };
+                    file.set(
+                        engine
+                            .read_file(&name)
+                            .await
+                            .map(|bytes| WasmFile {
+                                md5: format!("{:x}", md5::compute(&bytes)),
+                                name,
+                                bytes,
+                            }),
+                    );
+                },
+            }
+            if let Some(wasm) = file() {
+                small { "MD5 {wasm.md5} ({wasm.bytes.len()} bytes)" }
+            }
+            label { "Circuit Size" }
+            select {
+                value: "{circuit_size}",
+                onchange: move |evt| circuit_size.set(evt.value().parse().unwrap_or(DEFAULT_CIRCUIT_SIZE)),
+                {CIRCUIT_SIZES.iter().map(|it| rsx! {
+                    option { value: "{it}", "{it}" }
+                })}
+            }
+            label { "Description URL" }
+            input {
+                r#type: "text",
+                value: description_url(),
+                oninput: move |evt| description_url.set(evt.value()),
+            }
+            {config.render(move |config| rsx! {
+                NetworkCheckboxes { config, selected: networks }
+            })}
+            label { "Prove Payment Source" }
+            select {
+                value: enum_label(&payment()),
+                onchange: move |evt| {
+                    if let Ok(it) = enum_from_string(&evt.value()) {
+                        payment.set(it);
+                    }
+                },
+                {[ProvePaymentSrc::Default, ProvePaymentSrc::CreatorPay].iter().map(|it| rsx! {
+                    option { value: enum_label(it), {enum_label(it)} }
+                })}
+            }
+            label { "Add Prove Task Restrictions" }
+            select {
+                value: enum_label(&restrictions()),
+                onchange: move |evt| {
+                    if let Ok(it) = enum_from_string(&evt.value()) {
+                        restrictions.set(it);
+                    }
+                },
+                {[AddProveTaskRestrictions::Anyone, AddProveTaskRestrictions::CreatorOnly].iter().map(|it| rsx! {
+                    option { value: enum_label(it), {enum_label(it)} }
+                })}
+            }
+            if account().is_none() {
+                small { "Connect MetaMask to sign the request" }
+            }
+            if let Some(e) = error() {
+                small { "{e}" }
+            }
+        }
+    }
+}
@@ -0,0 +1,221 @@
+use dioxus::prelude::*;
+use zkp_service_helper::interface::AddProveTaskRestrictions;
+use zkp_service_helper::interface::Image;
+use zkp_service_helper::interface::ProofSubmitMode;
+use zkp_service_helper::interface::ProvingParams;
+use zkp_service_helper::interface::WithSignature;
+use zkp_service_helper::util::ZkWasmUtil;
+
+use crate::components::modal::Modal;
+use crate::components::status::ErrorCard;
+use crate::utils::enum_from_string;
+use crate::utils::enum_label;
+use crate::utils::inputs::parse_inputs;
+use crate::utils::service::use_zk;
+use crate::utils::service::ZkService;
+use crate::utils::use_query;
+use crate::utils::web3_subscriber::sign_message;
+use crate::utils::web3_subscriber::WalletAccount;
+use crate::utils::Loadable;
+use crate::Route;
+
+async fn submit_prove(zk: &dyn ZkService, account: WalletAccount, params: ProvingParams) -> anyhow::Result<String> {
+    let message = ZkWasmUtil::create_proving_sign_message(&params);
+    let signature = sign_message(&account, &message)
+        .await
+        .map_err(|e| anyhow::anyhow!("Signing failed: {e:?}"))?;
+    zk.add_proving_task(WithSignature { params, signature }).await.map(|res| res.id)
+}
+
+fn can_add_prove(image: &Image, account: &WalletAccount) -> bool {
+    match image.add_prove_task_restrictions {
+        AddProveTaskRestrictions::Anyone => true,
+        AddProveTaskRestrictions::CreatorOnly => image.user_address.eq_ignore_ascii_case(account.address()),
+    }
+}
+
+#[component]
+fn InputErrors(errors: Vec<String>) -> Element {
+    rsx! {
+        {errors.into_iter().enumerate().map(|(i, e)| rsx! {
+            small { key: "{i}", "{e}" }
+        })}
+    }
+}
+
+#[component]
+pub fn AddProveForm(open: Signal<bool>, account: Signal<Option<WalletAccount>>) -> Element {
+    let zk = use_zk();
+    let config = use_query(move || zk.run(|zk| zk.query_config()));
+
+    let mut md5 = use_signal(String::new);
+    let mut image = use_query(move || {
+        let (zk, md5) = (zk.get(), md5());
+        async move {
+            if md5.len() != 32 {
+                return Ok(None);
+            }
+            zk.query_image(md5).await
+        }
+    });
+
+    let mut public_inputs = use_signal(String::new);
+    let mut private_inputs = use_signal(String::new);
+    let mut submit_mode = use_signal(|| ProofSubmitMode::Manual);
+    let mut error = use_signal(|| Option::<String>::None);
+    let mut submitting = use_signal(|| false);
+
+    let public_parsed = use_memo(move || parse_inputs(&public_inputs()));
+    let private_parsed = use_memo(move || parse_inputs(&private_inputs()));
+
+    let selected_image = use_memo(move || image.ready().flatten());
+    let networks = use_memo(move || {
+        let ids = selected_image().map(|it| it.auto_submit_network_ids).unwrap_or_default();
+        config
+            .ready()
+            .map(|it| it.chain_info_list)
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|it| ids.contains(&it.chain_id))
+            .map(|it| it.chain_name)
+            .collect::<Vec<_>>()
+    });
+    use_effect(move || {
+        if networks().is_empty() {
+            submit_mode.set(ProofSubmitMode::Manual);
+        }
+    });
+    let restricted = use_memo(move || {
+        selected_image()
+            .zip(account())
+            .is_some_and(|(img, acc)| !can_add_prove(&img, &acc))
+    });
+
+    let submit = move |_| async move {
+        let Some(acc) = account() else {
+            error.set(Some("Connect MetaMask before submitting".to_string()));
+            return;
+        };
+        let Some(img) = selected_image() else {
+            error.set(Some("Enter the MD5 of an existing image".to_string()));
+            return;
+        };
+        if !can_add_prove(&img, &acc) {
+            error.set(Some("Only the image creator can add prove tasks to this image".to_string()));
+            return;
+        }
+        let (Ok(public_inputs), Ok(private_inputs)) = (public_parsed(), private_parsed()) else {
+            error.set(Some("Fix the invalid inputs before submitting".to_string()));
+            return;
+        };
+        let params = ProvingParams {
+            user_address: acc.address().to_string(),
+            md5: img.md5,
+            public_inputs,
+            private_inputs,
+            proof_submit_mode: submit_mode(),
+        };
+        submitting.set(true);
+        error.set(None);
+        match submit_prove(&*zk.peek(), acc, params).await {
+            Ok(id) => {
+                open.set(false);
+                navigator().push(Route::TaskDetails { id });
+            }
+            Err(e) => {
+                tracing::error!("{e}");
+                error.set(Some(e.to_string()));
+            }
+        }
+        submitting.set(false);
+    };
+
+    let invalid = public_parsed().is_err() || private_parsed().is_err() || selected_image().is_none() || restricted();
+    rsx! {
+        Modal {
+            title: "Submit Prove Task",
+            open,
+            footer: rsx! {
+                button {
+                    class: "confirm",
+                    disabled: submitting() || invalid,
+                    onclick: submit,
+                    if submitting() {
+                        "Submitting..."
+                    } else {
+                        "Submit"
+                    }
+                }
+            },
+            label { "Image MD5" }
+            input {
+                r#type: "text",
+                placeholder: "32 character image MD5",
+                value: md5(),
+                oninput: move |evt| md5.set(evt.value().trim().to_lowercase()),
+            }
+            match (md5().len(), image.state()) {
+                (0, _) => rsx! {},
+                (32, Loadable::Loading) => rsx! {
+                    small { "Looking up image..." }
+                },
+                (32, Loadable::Failed(message)) => rsx! {
+                    ErrorCard { message, onretry: move |_| image.retry() }
+                },
+                (32, Loadable::Ready(Some(img))) => rsx! {
+                    small { "Circuit size {img.circuit_size}, {img.status}" }
+                },
+                (32, Loadable::Ready(None)) => rsx! {
+                    small { "No image found for this MD5" }
+                },
+                _ => rsx! {
+                    small { "An MD5 is 32 hex characters" }
+                },
+            }
+            if restricted() {
+                small { "Only the image creator can add prove tasks to this image" }
+            }
+            label { "Public Inputs" }
+            textarea {
+                placeholder: "One input per line, e.g. 42:i64 or 0x0102:bytes",
+                value: public_inputs(),
+                oninput: move |evt| public_inputs.set(evt.value()),
+            }
+            if let Err(errors) = public_parsed() {
+                InputErrors { errors }
+            }
+            label { "Private Inputs" }
+            textarea {
+                placeholder: "One input per line, e.g. 42:i64 or 0x0102:bytes-packed",
+                value: private_inputs(),
+                oninput: move |evt| private_inputs.set(evt.value()),
+            }
+            if let Err(errors) = private_parsed() {
+                InputErrors { errors }
+            }
+            label { "Proof Submit Mode" }
+            select {
+                value: enum_label(&submit_mode()),
+                onchange: move |evt| {
+                    if let Ok(mode) = enum_from_string(&evt.value()) {
+                        submit_mode.set(mode);
+                    }
+                },
+                option { value: enum_label(&ProofSubmitMode::Manual),
+                    {enum_label(&ProofSubmitMode::Manual)}
+                }
+                if !networks().is_empty() {
+                    option { value: enum_label(&ProofSubmitMode::Auto),
+                        {format!("{} ({})", enum_label(&ProofSubmitMode::Auto), networks().join(", "))}
+                    }
+                }
+            }
+            if account().is_none() {
+                small { "Connect MetaMask to sign the request" }
+            }
+            if let Some(e) = error() {
+                small { "{e}" }
+            }
+        }
+    }
+}
@@ -0,0 +1,11 @@
+mod add_image;
+pub(super) use add_image::AddImageForm;
+
+mod add_prove;
+pub(super) use add_prove::AddProveForm;
+
+mod reset_image;
+pub(super) use reset_image::ResetImageForm;
+
+mod submit_proof;
+pub(super) use submit_proof::SubmitProofForm;
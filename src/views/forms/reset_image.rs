@@ -0,0 +1,102 @@
+use dioxus::prelude::*;
+use zkp_service_helper::interface::AppConfig;
+use zkp_service_helper::interface::Image;
+use zkp_service_helper::interface::ResetImageParams;
+use zkp_service_helper::interface::WithSignature;
+use zkp_service_helper::util::ZkWasmUtil;
+
+use super::add_image::NetworkCheckboxes;
+use super::add_image::CIRCUIT_SIZES;
+use crate::components::modal::Modal;
+use crate::utils::service::use_zk;
+use crate::utils::service::ZkService;
+use crate::utils::web3_subscriber::sign_message;
+use crate::utils::web3_subscriber::WalletAccount;
+
+async fn submit_reset(zk: &dyn ZkService, account: WalletAccount, params: ResetImageParams) -> anyhow::Result<String> {
+    let message = ZkWasmUtil::create_reset_image_sign_message(&params);
+    let signature = sign_message(&account, &message)
+        .await
+        .map_err(|e| anyhow::anyhow!("Signing failed: {e:?}"))?;
+    zk.add_reset_task(WithSignature { params, signature }).await.map(|res| res.id)
+}
+
+#[component]
+pub fn ResetImageForm(
+    open: Signal<bool>,
+    account: Signal<Option<WalletAccount>>,
+    image: Image,
+    config: Option<AppConfig>,
+    onsubmitted: EventHandler<String>,
+) -> Element {
+    let current_size = image.circuit_size;
+    let current_networks = image.auto_submit_network_ids.clone();
+    let mut circuit_size = use_signal(move || current_size);
+    let networks = use_signal(move || current_networks);
+    let mut error = use_signal(|| Option::<String>::None);
+    let mut submitting = use_signal(|| false);
+    let zk = use_zk();
+
+    let submit = move |_| {
+        let image = image.clone();
+        async move {
+            let Some(acc) = account() else {
+                error.set(Some("Connect MetaMask before submitting".to_string()));
+                return;
+            };
+            let params = ResetImageParams {
+                md5: image.md5,
+                circuit_size: circuit_size(),
+                user_address: acc.address().to_string(),
+                prove_payment_src: image.prove_payment_src,
+                auto_submit_network_ids: networks(),
+                add_prove_task_restrictions: image.add_prove_task_restrictions,
+            };
+            submitting.set(true);
+            error.set(None);
+            match submit_reset(&*zk.peek(), acc, params).await {
+                Ok(id) => {
+                    open.set(false);
+                    onsubmitted.call(id);
+                }
+                Err(e) => {
+                    tracing::error!("{e}");
+                    error.set(Some(e.to_string()));
+                }
+            }
+            submitting.set(false);
+        }
+    };
+
+    rsx! {
+        Modal {
+            title: "Reset Image",
+            open,
+            footer: rsx! {
+                button {
+                    class: "confirm",
+                    disabled: submitting(),
+                    onclick: submit,
+                    if submitting() {
+                        "Submitting..."
+                    } else {
+                        "Submit"
+                    }
+                }
+            },
+            label { "Circuit Size" }
+            select {
+                value: "{circuit_size}",
+                onchange: move |evt| circuit_size.set(evt.value().parse().unwrap_or(current_size)),
+                {CIRCUIT_SIZES.iter().map(|it| rsx! {
+                    option { value: "{it}", "{it}" }
+                })}
+            }
+            NetworkCheckboxes { config, selected: networks }
+            small { "A reset task re-runs the image setup with the new parameters" }
+            if let Some(e) = error() {
+                small { "{e}" }
+            }
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use dioxus::prelude::*;
+use zkp_service_helper::interface::AppConfig;
+
+use crate::components::modal::Modal;
+use crate::components::status::ErrorCard;
+use crate::utils::proof::ProofBundle;
+use crate::utils::service::use_zk;
+use crate::utils::use_query;
+use crate::utils::web3_subscriber::send_transaction;
+use crate::utils::web3_subscriber::switch_chain;
+use crate::utils::web3_subscriber::transaction_status;
+use crate::utils::web3_subscriber::WalletAccount;
+use crate::utils::Loadable;
+
+const RECEIPT_POLL_MS: u32 = 3000;
+
+#[derive(Clone, PartialEq)]
+struct Verifier {
+    chain_id: u64,
+    chain_name: String,
+    address: String,
+}
+
+fn verifiers(config: &AppConfig) -> Vec<Verifier> {
+    config
+        .chain_info_list
+        .iter()
+        .filter_map(|chain| {
+            config
+                .deployments
+                .iter()
+                .find(|it| it.chain_id == chain.chain_id)
+                .map(|it| Verifier {
+                    chain_id: chain.chain_id,
+                    chain_name: chain.chain_name.clone(),
+                    address: it.address.verifier_contract.clone(),
+                })
+        })
+        .collect()
+}
+
+#[derive(Clone, PartialEq)]
+enum TxState {
+    Idle,
+    Sending,
+    Pending(String),
+    Mined(String, bool),
+    Failed(String),
+}
+
+async fn send_verify(account: &WalletAccount, verifier: &Verifier, bundle: &ProofBundle) -> anyhow::Result<String> {
+    let js_err = |e| anyhow::anyhow!("{e:?}");
+    switch_chain(verifier.chain_id).await.map_err(js_err)?;
+    send_transaction(account, &verifier.address, &bundle.verify_calldata())
+        .await
+        .map_err(js_err)
+}
+
+#[component]
+pub fn SubmitProofForm(open: Signal<bool>, account: Signal<Option<WalletAccount>>, bundle: ProofBundle) -> Element {
+    let zk = use_zk();
+    let mut config = use_query(move || zk.run(|zk| zk.query_config()));
+
+    let options = use_memo(move || config.ready().map(|it| verifiers(&it)).unwrap_or_default());
+    let mut selected = use_signal(|| Option::<u64>::None);
+    use_effect(move || {
+        if selected.peek().is_none() {
+            selected.set(options().first().map(|it| it.chain_id));
+        }
+    });
+    let mut state = use_signal(|| TxState::Idle);
+
+    let submit = move |_| {
+        let bundle = bundle.clone();
+        async move {
+            let Some(acc) = account() else {
+                state.set(TxState::Failed("Connect MetaMask before submitting".to_string()));
+                return;
+            };
+            let Some(verifier) = options().into_iter().find(|it| Some(it.chain_id) == selected()) else {
+                state.set(TxState::Failed("Select a network with a deployed verifier".to_string()));
+                return;
+            };
+            state.set(TxState::Sending);
+            let hash = match send_verify(&acc, &verifier, &bundle).await {
+                Ok(hash) => hash,
+                Err(e) => {
+                    tracing::error!("{e}");
+                    state.set(TxState::Failed(e.to_string()));
+                    return;
+                }
+            };
+            state.set(TxState::Pending(hash.clone()));
+            loop {
+                match transaction_status(&hash).await {
+                    Ok(Some(success)) => {
+                        state.set(TxState::Mined(hash, success));
+                        return;
+                    }
+                    Ok(None) => gloo_timers::future::TimeoutFuture::new(RECEIPT_POLL_MS).await,
+                    Err(e) => {
+                        tracing::error!("{e:?}");
+                        state.set(TxState::Failed(format!("Failed to fetch receipt for {hash}: {e:?}")));
+                        return;
+                    }
+                }
+            }
+        }
+    };
+
+    let busy = matches!(state(), TxState::Sending | TxState::Pending(_));
+    rsx! {
+        Modal {
+            title: "Submit Proof On-chain",
+            open,
+            footer: rsx! {
+                button {
+                    class: "confirm",
+                    disabled: busy || options().is_empty(),
+                    onclick: submit,
+                    if busy {
+                        "Submitting..."
+                    } else {
+                        "Send with MetaMask"
+                    }
+                }
+            },
+            label { "Network" }
+            select {
+                value: selected().map(|it| it.to_string()).unwrap_or_default(),
+                onchange: move |evt| selected.set(evt.value().parse().ok()),
+                {options().into_iter().map(|it| rsx! {
+                    option { value: "{it.chain_id}", "{it.chain_name}" }
+                })}
+            }
+            if let Loadable::Failed(message) = config.state() {
+                ErrorCard { message, onretry: move |_| config.retry() }
+            } else if let Some(verifier) = options().into_iter().find(|it| Some(it.chain_id) == selected()) {
+                small { "Verifier {verifier.address}" }
+            } else {
+                small { "No verifier deployments found" }
+            }
+            match state() {
+                TxState::Idle => rsx! {},
+                TxState::Sending => rsx! {
+                    small { "Waiting for MetaMask..." }
+                },
+                TxState::Pending(hash) => rsx! {
+                    small { "Transaction {hash} pending" }
+                },
+                TxState::Mined(hash, true) => rsx! {
+                    small { "Transaction {hash} succeeded" }
+                },
+                TxState::Mined(hash, false) => rsx! {
+                    small { "Transaction {hash} reverted" }
+                },
+                TxState::Failed(e) => rsx! {
+                    small { "{e}" }
+                },
+            }
+        }
+    }
+}
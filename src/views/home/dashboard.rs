@@ -5,40 +5,134 @@ use zkp_service_helper::interface::TaskType;
 use super::AutoSubmitTaskTables;
 use super::ConciseTaskTables;
 use super::ProverTaskTables;
+use super::SubmitRange;
 use super::TaskSummary;
+use crate::components::search::DateRangePicker;
 use crate::components::search::Search;
 use crate::components::search::SearchSelectLike;
+use crate::utils::live::LiveInterval;
+use crate::utils::live::LiveToggle;
+use crate::utils::preferences::use_preferences;
+use crate::utils::service::use_zk;
+use crate::views::search::resolve_query;
+use crate::views::search::QueryShape;
+use crate::Route;
 use crate::GLOBAL_PADDING;
 
+fn non_empty(it: &str) -> Option<String> {
+    Some(it.to_string()).filter(|it| !it.is_empty())
+}
+
+/// The filters and page live in the URL, `page` is 1-based so shared links match the page shown.
 #[component]
-pub fn Dashboard() -> Element {
-    let query = use_signal(|| Option::<String>::None);
-    let tasktype = use_signal(|| Option::<TaskType>::None);
-    let taskstatus = use_signal(|| Option::<TaskStatus>::None);
-    let trigger = use_signal(|| false);
+pub fn Dashboard(q: String, task_type: String, status: String, after: String, before: String, page: u64) -> Element {
+    let mut query = use_signal(|| non_empty(&q));
+    let mut tasktype = use_signal(|| TaskType::from_query(&task_type));
+    let mut taskstatus = use_signal(|| TaskStatus::from_query(&status));
+    let mut submitted_after = use_signal(|| non_empty(&after));
+    let mut submitted_before = use_signal(|| non_empty(&before));
+    let mut curr = use_signal(|| page.saturating_sub(1));
+    let mut trigger = use_signal(|| false);
+    let zk = use_zk();
+    let prefs = use_preferences();
+    use_context_provider(|| LiveInterval(Signal::new(None)));
 
-    let inputs = use_memo(move || {
-        if trigger() {
-            Some((query(), tasktype(), taskstatus()))
+    // Back/forward and pasted links change the route, restore the view from it
+    use_effect(use_reactive!(|q, task_type, status, after, before, page| {
+        query.set(non_empty(&q));
+        tasktype.set(TaskType::from_query(&task_type));
+        taskstatus.set(TaskStatus::from_query(&status));
+        submitted_after.set(non_empty(&after));
+        submitted_before.set(non_empty(&before));
+        curr.set(page.saturating_sub(1));
+    }));
+
+    // Mirror the filters and page into the URL, a filter change starts again from the first page.
+    // Typing in the search box replaces the entry on every keystroke, page and filter changes push one
+    use_effect(move || {
+        let filters = (
+            query().unwrap_or_default(),
+            TaskType::to_query(&tasktype()),
+            TaskStatus::to_query(&taskstatus()),
+            submitted_after().unwrap_or_default(),
+            submitted_before().unwrap_or_default(),
+        );
+        let Route::Dashboard {
+            q,
+            task_type,
+            status,
+            after,
+            before,
+            page: prev_page,
+        } = router().current::<Route>()
+        else {
+            return;
+        };
+        let query_changed = q != filters.0;
+        let selects_changed =
+            (&task_type, &status, &after, &before) != (&filters.1, &filters.2, &filters.3, &filters.4);
+        if query_changed || selects_changed {
+            curr.set(0);
+        } else if curr() + 1 == prev_page.max(1) {
+            return;
+        }
+        let (q, task_type, status, after, before) = filters;
+        let page = curr() + 1;
+        let href = Route::Dashboard { q, task_type, status, after, before, page }.href();
+        if query_changed && !selects_changed {
+            navigator().replace(href);
         } else {
-            None
+            navigator().push(href);
+        }
+    });
+
+    // Typing filters the task history, submitting the query opens its details page or the search results
+    use_effect(move || {
+        if !trigger() {
+            return;
+        }
+        trigger.set(false);
+        let q = query.peek().clone().unwrap_or_default().trim().to_string();
+        if q.is_empty() {
+            return;
         }
+        let zk = zk.peek();
+        spawn(async move {
+            // A failed lookup still opens the results page, which shows the error with a retry
+            let route = match resolve_query(&*zk, &q).await {
+                Ok(matches) => matches.route(q),
+                Err(_) => Route::SearchResults { q },
+            };
+            navigator().push(route);
+        });
+    });
+
+    let inputs = use_memo(move || {
+        let range = SubmitRange {
+            after: submitted_after(),
+            before: submitted_before(),
+            time_zone: prefs.read().time.time_zone,
+        };
+        let filter = QueryShape::parse(&query().unwrap_or_default());
+        Some((filter, tasktype(), taskstatus(), range))
     });
 
     rsx! {
         div { style: GLOBAL_PADDING,
             Search {
                 title: "The ZKWASM Task Explorer",
-                placeholder: "Enter an MD5 hash, 0x address, or task ID",
+                placeholder: "Search by 0x address, image MD5, task ID or prover node",
                 input: query,
                 trigger,
                 sel1: tasktype,
                 sel2: taskstatus,
             }
+            DateRangePicker { after: submitted_after, before: submitted_before }
+            LiveToggle {}
         }
         TaskSummary {}
         ProverTaskTables {}
-        ConciseTaskTables { inputs }
+        ConciseTaskTables { inputs, page: curr }
         AutoSubmitTaskTables {}
     }
 }
@@ -46,11 +140,11 @@ pub fn Dashboard() -> Element {
 macro_rules! enum_string_conversions {
     () => {
         fn to_string(it: &Self) -> String {
-            $crate::utils::enum_to_string(it)
+            $crate::utils::enum_label(it)
         }
 
-        fn from_string(it: String) -> Self {
-            $crate::utils::enum_from_string(&it)
+        fn from_string(it: String) -> Option<Self> {
+            $crate::utils::enum_from_string(&it).ok()
         }
     };
 }
@@ -4,6 +4,7 @@ mod task_tables;
 pub(super) use task_tables::AutoSubmitTaskTables;
 pub(super) use task_tables::ConciseTaskTables;
 pub(super) use task_tables::ProverTaskTables;
+pub(super) use task_tables::SubmitRange;
 
 mod task_summary;
 pub(super) use task_summary::TaskSummary;
@@ -9,21 +9,17 @@ use zkp_service_helper::interface::StatisticsInfo;
 use crate::components::card::CardWithIcon;
 use crate::components::card::SummaryCardLike;
 use crate::utils::ZkEntry;
-use crate::GLOBAL_PADDING;
 
-impl SummaryCardLike for Option<StatisticsInfo> {
+impl SummaryCardLike for StatisticsInfo {
     type T = ZkEntry;
 
     fn entries(self) -> Vec<(Self::T, Self::T, Self::T, Self::T)> {
-        self.map(|it| {
-            vec![(
-                ZkEntry::Raw(format!("{} applications", it.total_images)),
-                ZkEntry::Raw(format!("{} tasks", it.total_tasks)),
-                ZkEntry::Raw(format!("{} proofs", it.total_proofs)),
-                ZkEntry::Raw(format!("{} seconds", 40)),
-            )]
-        })
-        .unwrap_or_default()
+        vec![(
+            ZkEntry::Raw(format!("{} applications", self.total_images)),
+            ZkEntry::Raw(format!("{} tasks", self.total_tasks)),
+            ZkEntry::Raw(format!("{} proofs", self.total_proofs)),
+            ZkEntry::Raw(format!("{} seconds", 40)),
+        )]
     }
 }
 
@@ -36,36 +32,34 @@ pub fn StatsSummary<U: SummaryCardLike + Clone + PartialEq + 'static>(data: U) -
     };
 
     rsx! {
-        div { style: GLOBAL_PADDING,
-            div { class: "stats-container",
-                CardWithIcon {
-                    title: "Applications in total",
-                    text: entries.0.clone(),
-                    icon: rsx! {
-                        Icon { icon: IoHardwareChipSharp, width: size, height: size }
-                    },
-                }
-                CardWithIcon {
-                    title: "Total Tasks Submitted",
-                    text: entries.1.clone(),
-                    icon: rsx! {
-                        Icon { icon: IoList, width: size, height: size }
-                    },
-                }
-                CardWithIcon {
-                    title: "Proofs generated in total",
-                    text: entries.2.clone(),
-                    icon: rsx! {
-                        Icon { icon: LdBinary, width: size, height: size }
-                    },
-                }
-                CardWithIcon {
-                    title: "Average Proving Time",
-                    text: entries.3.clone(),
-                    icon: rsx! {
-                        Icon { icon: FaClock, width: size, height: size }
-                    },
-                }
+        div { class: "stats-container",
+            CardWithIcon {
+                title: "Applications in total",
+                text: entries.0.clone(),
+                icon: rsx! {
+                    Icon { icon: IoHardwareChipSharp, width: size, height: size }
+                },
+            }
+            CardWithIcon {
+                title: "Total Tasks Submitted",
+                text: entries.1.clone(),
+                icon: rsx! {
+                    Icon { icon: IoList, width: size, height: size }
+                },
+            }
+            CardWithIcon {
+                title: "Proofs generated in total",
+                text: entries.2.clone(),
+                icon: rsx! {
+                    Icon { icon: LdBinary, width: size, height: size }
+                },
+            }
+            CardWithIcon {
+                title: "Average Proving Time",
+                text: entries.3.clone(),
+                icon: rsx! {
+                    Icon { icon: FaClock, width: size, height: size }
+                },
             }
         }
     }
@@ -1,10 +1,12 @@
 use dioxus::prelude::*;
+use std::rc::Rc;
 use zkp_service_helper::interface::ConciseTask;
 use zkp_service_helper::interface::StatisticsInfo;
 use zkp_service_helper::interface::TaskType;
 
 use crate::components::card::SummaryCard;
 use crate::components::card::SummaryCardLike;
+use crate::utils::service::ZkService;
 use crate::utils::AddressKind;
 use crate::utils::AddressStyle;
 use crate::utils::QueryFunctionHandler;
@@ -12,13 +14,12 @@ use crate::utils::TimestampStyle;
 use crate::utils::ZkEntry;
 use crate::views::home::StatsSummary;
 use crate::GLOBAL_PADDING;
-use crate::ZKH;
 
 impl QueryFunctionHandler for StatisticsInfo {
-    type Data = Option<Self>;
+    type Data = Self;
 
-    async fn query(_: ()) -> anyhow::Result<Self::Data> {
-        ZKH.query_statistics().await.map(Some)
+    async fn query(zk: Rc<dyn ZkService>, _: ()) -> anyhow::Result<Self::Data> {
+        zk.query_statistics().await
     }
 }
 
@@ -31,8 +32,8 @@ impl QueryFunctionHandler for ConciseTaskSummary {
     type Input = TaskType;
     type Data = Vec<ConciseTask>;
 
-    async fn query(inp: TaskType) -> anyhow::Result<Self::Data> {
-        ZKH.query_concise_tasks(None, None, None, Some(inp), None, None, Some(5))
+    async fn query(zk: Rc<dyn ZkService>, inp: TaskType) -> anyhow::Result<Self::Data> {
+        zk.query_concise_tasks(None, None, None, Some(inp), None, None, Some(5))
             .await
             .map(|res| res.data)
     }
@@ -63,24 +64,69 @@ pub fn TaskSummary() -> Element {
     let proves = ConciseTaskSummary::fetch_resource(TaskType::Prove);
 
     rsx! {
-        StatsSummary { data: stats() }
+        div { style: GLOBAL_PADDING,
+            {stats.render(|data| rsx! {
+                StatsSummary { data }
+            })}
+        }
         div { id: "adjacent-task-summaries", style: GLOBAL_PADDING,
-            SummaryCard {
-                data: ConciseTaskSummary {
-                    data: setups(),
-                },
-                header: "Latest Setups",
-                header_class: "aqua",
-                div_style: "padding: 0rem 0.5rem 0rem 0rem;",
-            }
-            SummaryCard {
-                data: ConciseTaskSummary {
-                    data: proves(),
-                },
-                header: "Latest Proofs",
-                header_class: "light-blue",
-                div_style: "padding: 0rem 0rem 0rem 0.5rem;",
-            }
+            {setups.render(|data| rsx! {
+                SummaryCard {
+                    data: ConciseTaskSummary { data },
+                    header: "Latest Setups",
+                    header_class: "aqua",
+                    div_style: "padding: 0rem 0.5rem 0rem 0rem;",
+                }
+            })}
+            {proves.render(|data| rsx! {
+                SummaryCard {
+                    data: ConciseTaskSummary { data },
+                    header: "Latest Proofs",
+                    header_class: "light-blue",
+                    div_style: "padding: 0rem 0rem 0rem 0.5rem;",
+                }
+            })}
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::fixtures;
+    use crate::testing::minimal;
+    use crate::testing::render;
+
+    fn render_summary(data: Vec<ConciseTask>, statistics: Option<StatisticsInfo>) -> String {
+        render(move || {
+            rsx! {
+                if let Some(data) = statistics.clone() {
+                    StatsSummary { data }
+                }
+                SummaryCard {
+                    data: ConciseTaskSummary { data: data.clone() },
+                    header: "Latest Setups",
+                    header_class: "aqua",
+                    div_style: "",
+                }
+            }
+        })
+    }
+
+    #[test]
+    fn task_summary() {
+        let fixtures = fixtures();
+        insta::assert_snapshot!(render_summary(fixtures.concise_tasks, fixtures.statistics));
+    }
+
+    #[test]
+    fn task_summary_with_missing_fields() {
+        let minimal = minimal();
+        insta::assert_snapshot!(render_summary(minimal.concise_tasks, minimal.statistics));
+    }
+
+    #[test]
+    fn empty_task_summary() {
+        insta::assert_snapshot!(render_summary(vec![], None));
+    }
+}
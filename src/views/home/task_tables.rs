@@ -1,6 +1,13 @@
 use dioxus::prelude::*;
+use serde::Serialize;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::rc::Weak;
+use web_time::Duration;
+use web_time::Instant;
 use zkp_service_helper::interface::AutoSubmitProof;
 use zkp_service_helper::interface::ConciseTask;
+use zkp_service_helper::interface::PaginationResult;
 use zkp_service_helper::interface::ProverNode;
 use zkp_service_helper::interface::Round1Info;
 use zkp_service_helper::interface::Round2Info;
@@ -10,15 +17,24 @@ use zkp_service_helper::interface::TaskType;
 use crate::components::table::PaginatedTable;
 use crate::components::table::PaginatedTableLike;
 use crate::components::table::PaginatedTableNoInputs;
+use crate::components::table::QueryTable;
 use crate::components::table::TableLike;
-use crate::utils::enum_to_string;
+use crate::utils::enum_label;
+use crate::utils::parse_datetime_local;
+use crate::utils::service::use_zk;
+use crate::utils::service::ZkClient;
+use crate::utils::service::ZkService;
+use crate::utils::use_query;
 use crate::utils::AddressKind;
 use crate::utils::AddressStyle;
+use crate::utils::DisplayTimeZone;
 use crate::utils::TimestampStyle;
-use crate::utils::UnwrapOrEmpty;
 use crate::utils::UnwrapOrNA;
 use crate::utils::ZkEntry;
-use crate::ZKH;
+use crate::views::detailed::node::scan_nodes;
+use crate::views::detailed::node::NodeScan;
+use crate::views::detailed::node::NODE_SCAN_LIMIT;
+use crate::views::search::QueryShape;
 
 impl TableLike for Vec<ProverNode> {
     fn title(&self) -> &str {
@@ -41,9 +57,9 @@ impl TableLike for Vec<ProverNode> {
             .map(|row| {
                 vec![
                     ZkEntry::Address(row.address.clone(), AddressStyle::Dashboard, AddressKind::Node),
-                    ZkEntry::Raw(row.statistics.successful_tasks.to_string()),
-                    ZkEntry::Raw(row.statistics.failed_tasks.to_string()),
-                    ZkEntry::Raw(row.statistics.total_tasks.to_string()),
+                    ZkEntry::Number(row.statistics.successful_tasks.to_string()),
+                    ZkEntry::Number(row.statistics.failed_tasks.to_string()),
+                    ZkEntry::Number(row.statistics.total_tasks.to_string()),
                     ZkEntry::Raw(
                         row.statistics
                             .proof_timing_stats
@@ -59,17 +75,39 @@ impl TableLike for Vec<ProverNode> {
             })
             .collect()
     }
+
+    fn row_keys(&self) -> Vec<String> {
+        self.iter().map(|row| row.address.clone()).collect()
+    }
 }
 
-impl PaginatedTableLike for Vec<ProverNode> {
-    fn n_per_paginated() -> u64 {
-        5
+/// Every prover node in one table, so sorting by a column ranks all of them rather than a page.
+#[derive(Clone, PartialEq)]
+struct ProverList {
+    nodes: Vec<ProverNode>,
+    truncated: bool,
+}
+
+impl TableLike for ProverList {
+    fn title(&self) -> &str {
+        self.nodes.title()
     }
 
-    fn query_function() -> Box<dyn Fn(u64, u64, Option<Self::Inputs>) -> Self::Fut> {
-        Box::new(move |page: u64, per: u64, _: Option<Self::Inputs>| {
-            Box::pin(async move { ZKH.query_node_statistics(None, Some(page), Some(per)).await.unwrap_or_empty() })
-        })
+    fn headers(&self) -> Vec<&str> {
+        self.nodes.headers()
+    }
+
+    fn rows(&self) -> Vec<Vec<ZkEntry>> {
+        self.nodes.rows()
+    }
+
+    fn row_keys(&self) -> Vec<String> {
+        self.nodes.row_keys()
+    }
+
+    fn notice(&self) -> Option<String> {
+        self.truncated
+            .then(|| format!("Results truncated, only the first {NODE_SCAN_LIMIT} prover nodes are listed."))
     }
 }
 
@@ -103,38 +141,205 @@ impl TableLike for Vec<ConciseTask> {
             })
             .collect()
     }
+
+    fn row_keys(&self) -> Vec<String> {
+        self.iter().map(|row| row._id.oid.clone()).collect()
+    }
+}
+
+/// Bounds on task submit time as `datetime-local` values, read in the zone timestamps are shown in.
+/// Bounds that don't parse are left out, the date range picker reports them.
+#[derive(Clone, PartialEq, Default, Serialize)]
+pub struct SubmitRange {
+    pub after: Option<String>,
+    pub before: Option<String>,
+    pub time_zone: DisplayTimeZone,
+}
+
+impl SubmitRange {
+    fn parse(&self, it: &Option<String>) -> Option<chrono::DateTime<chrono::Utc>> {
+        parse_datetime_local(it.as_deref()?, self.time_zone).ok()
+    }
+
+    fn is_set(&self) -> bool {
+        self.parse(&self.after).is_some() || self.parse(&self.before).is_some()
+    }
+
+    fn contains(&self, submit_time: &str) -> bool {
+        let Ok(ts) = submit_time.parse::<chrono::DateTime<chrono::Utc>>() else {
+            return false;
+        };
+        self.parse(&self.after).is_none_or(|it| ts >= it) && self.parse(&self.before).is_none_or(|it| ts < it)
+    }
+
+    fn is_past(&self, submit_time: &str) -> bool {
+        let Ok(ts) = submit_time.parse::<chrono::DateTime<chrono::Utc>>() else {
+            return false;
+        };
+        self.parse(&self.after).is_some_and(|it| ts < it)
+    }
+}
+
+/// The `query_concise_tasks` user, MD5 and task id filters a search query maps to.
+/// Addresses filter by the submitting user, the service can't list the tasks a prover node took.
+fn query_filters(query: Option<QueryShape>) -> (Option<String>, Option<String>, Option<String>) {
+    match query {
+        Some(QueryShape::Address(it)) => (Some(it), None, None),
+        Some(QueryShape::Md5(it)) => (None, Some(it), None),
+        Some(QueryShape::TaskId(it)) => (None, None, Some(it)),
+        None => (None, None, None),
+    }
+}
+
+const RANGE_SCAN_BATCH: u64 = 100;
+const RANGE_SCAN_LIMIT: u64 = 5000;
+/// How long a scan is paginated from before the next page or live refresh scans again.
+const RANGE_SCAN_TTL: Duration = Duration::from_secs(30);
+
+type TaskInputs = (Option<QueryShape>, Option<TaskType>, Option<TaskStatus>, SubmitRange);
+
+/// The tasks in a submit time range, `truncated` when the scan stopped at `RANGE_SCAN_LIMIT` before reaching the range.
+struct RangeScan {
+    tasks: Vec<ConciseTask>,
+    truncated: bool,
+}
+
+/// The last range scan, shared by every page, live refresh and export of the task history with the same filters.
+/// It keeps a weak reference to the service that ran it, which also stops a client built after switching
+/// environments from being allocated at the same address, so a scan is never served to another environment.
+#[derive(Clone, Default)]
+struct RangeScans(Rc<RefCell<Option<RangeScanEntry>>>);
+
+struct RangeScanEntry {
+    zk: Weak<dyn ZkService>,
+    key: String,
+    at: Instant,
+    scan: Rc<RangeScan>,
+}
+
+impl RangeScans {
+    fn get(&self, zk: &Rc<dyn ZkService>, key: &str) -> Option<Rc<RangeScan>> {
+        self.0
+            .borrow()
+            .as_ref()
+            .filter(|it| Weak::ptr_eq(&it.zk, &Rc::downgrade(zk)) && it.key == key && it.at.elapsed() < RANGE_SCAN_TTL)
+            .map(|it| it.scan.clone())
+    }
+
+    fn set(&self, zk: &Rc<dyn ZkService>, key: String, scan: Rc<RangeScan>) {
+        *self.0.borrow_mut() = Some(RangeScanEntry {
+            zk: Rc::downgrade(zk),
+            key,
+            at: Instant::now(),
+            scan,
+        });
+    }
+}
+
+/// The backend can't filter by submit time, so the newest first task list is scanned in batches
+/// until it passes the lower bound.
+async fn scan_concise_tasks_in_range(zk: &dyn ZkService, inputs: TaskInputs) -> anyhow::Result<RangeScan> {
+    let (query, tasktype, status, range) = inputs;
+    let (user_address, md5, id) = query_filters(query);
+    let mut tasks = vec![];
+    let mut offset = 0;
+    while offset < RANGE_SCAN_LIMIT {
+        let res = zk
+            .query_concise_tasks(
+                user_address.clone(),
+                md5.clone(),
+                id.clone(),
+                tasktype.clone(),
+                status.clone(),
+                Some(offset),
+                Some(RANGE_SCAN_BATCH),
+            )
+            .await?;
+        let exhausted = (res.data.len() as u64) < RANGE_SCAN_BATCH
+            || res.data.last().is_some_and(|it| range.is_past(&it.submit_time));
+        tasks.extend(res.data.into_iter().filter(|it| range.contains(&it.submit_time)));
+        if exhausted {
+            return Ok(RangeScan { tasks, truncated: false });
+        }
+        offset += RANGE_SCAN_BATCH;
+    }
+    tracing::warn!("Stopped scanning for tasks in range after {RANGE_SCAN_LIMIT} tasks");
+    Ok(RangeScan { tasks, truncated: true })
+}
+
+/// A page of the task history, noting when a date range scan couldn't reach every match.
+#[derive(Clone, PartialEq, Serialize)]
+pub struct TaskHistory {
+    tasks: Vec<ConciseTask>,
+    truncated: bool,
+}
+
+impl TableLike for TaskHistory {
+    fn title(&self) -> &str {
+        self.tasks.title()
+    }
+
+    fn headers(&self) -> Vec<&str> {
+        self.tasks.headers()
+    }
+
+    fn rows(&self) -> Vec<Vec<ZkEntry>> {
+        self.tasks.rows()
+    }
+
+    fn row_keys(&self) -> Vec<String> {
+        self.tasks.row_keys()
+    }
+
+    fn notice(&self) -> Option<String> {
+        self.truncated.then(|| {
+            format!("Results truncated, only the newest {RANGE_SCAN_LIMIT} tasks were searched for this date range.")
+        })
+    }
 }
 
 impl PaginatedTableLike for Vec<ConciseTask> {
-    type Inputs = (Option<String>, Option<TaskType>, Option<TaskStatus>);
+    type Inputs = TaskInputs;
+
+    type Data = TaskHistory;
 
     fn n_per_paginated() -> u64 {
         10
     }
 
-    fn query_function() -> Box<dyn Fn(u64, u64, Option<Self::Inputs>) -> Self::Fut> {
+    fn query_function(zk: ZkClient) -> Box<dyn Fn(u64, u64, Option<Self::Inputs>) -> Self::Fut> {
+        let scans = try_consume_context::<RangeScans>().unwrap_or_default();
         Box::new(move |page: u64, per: u64, inps: Option<Self::Inputs>| {
+            let (zk, scans) = (zk.get(), scans.clone());
             Box::pin(async move {
-                let query = inps.as_ref().and_then(|it| it.0.clone());
-                let tasktype = inps.as_ref().and_then(|it| it.1.clone());
-                let status = inps.as_ref().and_then(|it| it.2.clone());
-
-                let mut user_address = None;
-                let mut md5 = None;
-                let mut id = None;
-                if let Some(q) = query {
-                    if q.len() == 42 && q.starts_with("0x") {
-                        user_address = Some(q);
-                    } else if q.len() == 32 {
-                        md5 = Some(q);
-                    } else if q.len() == 24 {
-                        id = Some(q);
-                    }
+                let inputs = inps.unwrap_or_default();
+                if inputs.3.is_set() {
+                    let key = serde_json::to_string(&inputs)?;
+                    let scan = match scans.get(&zk, &key) {
+                        Some(scan) => scan,
+                        None => {
+                            let scan = Rc::new(scan_concise_tasks_in_range(&*zk, inputs).await?);
+                            scans.set(&zk, key, scan.clone());
+                            scan
+                        }
+                    };
+                    return Ok(PaginationResult {
+                        total: scan.tasks.len() as u64,
+                        data: TaskHistory {
+                            tasks: scan.tasks.iter().skip(page as usize).take(per as usize).cloned().collect(),
+                            truncated: scan.truncated,
+                        },
+                    });
                 }
-
-                ZKH.query_concise_tasks(user_address, md5, id, tasktype, status, Some(page), Some(per))
-                    .await
-                    .unwrap_or_empty()
+                let (query, tasktype, status, _) = inputs;
+                let (user_address, md5, id) = query_filters(query);
+                let res = zk
+                    .query_concise_tasks(user_address, md5, id, tasktype, status, Some(page), Some(per))
+                    .await?;
+                Ok(PaginationResult {
+                    total: res.total,
+                    data: TaskHistory { tasks: res.data, truncated: false },
+                })
             })
         })
     }
@@ -160,7 +365,7 @@ impl TableLike for Vec<AutoSubmitProof> {
             .map(|row| {
                 vec![
                     ZkEntry::MaybeAddress(row._id.clone().map(|it| it.oid), AddressStyle::Dashboard, AddressKind::Task),
-                    ZkEntry::Raw(enum_to_string(&row.status)),
+                    ZkEntry::Raw(enum_label(&row.status)),
                     ZkEntry::Timestamp(row.batch_started.clone(), TimestampStyle::Full),
                     ZkEntry::Raw(row.auto_submit_network_chain_id.to_string()),
                     ZkEntry::Timestamp(row.batch_finished.clone(), TimestampStyle::Full),
@@ -175,12 +380,12 @@ impl PaginatedTableLike for Vec<AutoSubmitProof> {
         5
     }
 
-    fn query_function() -> Box<dyn Fn(u64, u64, Option<Self::Inputs>) -> Self::Fut> {
+    fn query_function(zk: ZkClient) -> Box<dyn Fn(u64, u64, Option<Self::Inputs>) -> Self::Fut> {
         Box::new(move |page: u64, per: u64, _: Option<Self::Inputs>| {
+            let zk = zk.get();
             Box::pin(async move {
-                ZKH.query_auto_submit_proofs(None, None, None, None, None, Some(page), Some(per))
+                zk.query_auto_submit_proofs(None, None, None, None, None, Some(page), Some(per))
                     .await
-                    .unwrap_or_empty()
             })
         })
     }
@@ -206,7 +411,7 @@ impl TableLike for Vec<Round1Info> {
             .map(|row| {
                 vec![
                     ZkEntry::MaybeAddress(row._id.clone().map(|it| it.oid), AddressStyle::Dashboard, AddressKind::Task),
-                    ZkEntry::Raw(enum_to_string(&row.status)),
+                    ZkEntry::Raw(enum_label(&row.status)),
                     ZkEntry::Timestamp(row.batch_started.clone(), TimestampStyle::Full),
                     ZkEntry::Raw(row.auto_submit_network_chain_id.to_string()),
                     ZkEntry::Timestamp(row.batch_finished.clone(), TimestampStyle::Full),
@@ -221,12 +426,12 @@ impl PaginatedTableLike for Vec<Round1Info> {
         5
     }
 
-    fn query_function() -> Box<dyn Fn(u64, u64, Option<Self::Inputs>) -> Self::Fut> {
+    fn query_function(zk: ZkClient) -> Box<dyn Fn(u64, u64, Option<Self::Inputs>) -> Self::Fut> {
         Box::new(move |page: u64, per: u64, _: Option<Self::Inputs>| {
+            let zk = zk.get();
             Box::pin(async move {
-                ZKH.query_round1_info(None, None, None, None, None, None, Some(page), Some(per))
+                zk.query_round1_info(None, None, None, None, None, None, Some(page), Some(per))
                     .await
-                    .unwrap_or_empty()
             })
         })
     }
@@ -260,28 +465,38 @@ impl PaginatedTableLike for Vec<Round2Info> {
         5
     }
 
-    fn query_function() -> Box<dyn Fn(u64, u64, Option<Self::Inputs>) -> Self::Fut> {
+    fn query_function(zk: ZkClient) -> Box<dyn Fn(u64, u64, Option<Self::Inputs>) -> Self::Fut> {
         Box::new(move |page: u64, per: u64, _: Option<Self::Inputs>| {
-            Box::pin(async move {
-                ZKH.query_round2_info(None, None, None, None, None, Some(page), Some(per))
-                    .await
-                    .unwrap_or_empty()
-            })
+            let zk = zk.get();
+            Box::pin(async move { zk.query_round2_info(None, None, None, None, None, Some(page), Some(per)).await })
         })
     }
 }
 
 #[component]
 pub fn ProverTaskTables() -> Element {
+    let zk = use_zk();
+    let query = use_query(move || {
+        zk.run(|zk| {
+            Box::pin(async move {
+                let NodeScan { nodes, truncated } = scan_nodes(zk).await?;
+                Ok(ProverList { nodes, truncated })
+            })
+        })
+    });
     rsx! {
-        PaginatedTableNoInputs::<Vec<ProverNode>> {}
+        QueryTable { query }
     }
 }
 
 #[component]
-pub fn ConciseTaskTables(inputs: Memo<Option<<Vec<ConciseTask> as PaginatedTableLike>::Inputs>>) -> Element {
+pub fn ConciseTaskTables(
+    inputs: Memo<Option<<Vec<ConciseTask> as PaginatedTableLike>::Inputs>>,
+    page: Signal<u64>,
+) -> Element {
+    use_context_provider(RangeScans::default);
     rsx! {
-        PaginatedTable::<Vec<ConciseTask>> { inputs }
+        PaginatedTable::<Vec<ConciseTask>> { inputs, page }
     }
 }
 
@@ -293,3 +508,70 @@ pub fn AutoSubmitTaskTables() -> Element {
         PaginatedTableNoInputs::<Vec<Round2Info>> {}
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use zkp_service_helper::helper::ZkWasmServiceHelper;
+
+    use super::*;
+    use crate::components::table::Table;
+    use crate::testing::fixtures;
+    use crate::testing::minimal;
+    use crate::testing::render;
+    use crate::utils::mock::Fixtures;
+
+    fn render_table<T: TableLike + PartialEq + Clone + 'static>(data: T) -> String {
+        render(move || {
+            rsx! {
+                Table { data: data.clone() }
+            }
+        })
+    }
+
+    fn render_tables(fixtures: Fixtures) -> String {
+        [
+            render_table(fixtures.nodes),
+            render_table(fixtures.concise_tasks),
+            render_table(fixtures.auto_submit_proofs),
+            render_table(fixtures.round1_info),
+            render_table(fixtures.round2_info),
+        ]
+        .join("\n")
+    }
+
+    #[test]
+    fn tables() {
+        insta::assert_snapshot!(render_tables(fixtures()));
+    }
+
+    #[test]
+    fn tables_with_missing_fields() {
+        insta::assert_snapshot!(render_tables(minimal()));
+    }
+
+    #[test]
+    fn empty_tables() {
+        insta::assert_snapshot!(render_tables(Fixtures::default()));
+    }
+
+    #[test]
+    fn truncated_task_history() {
+        insta::assert_snapshot!(render_table(TaskHistory { tasks: fixtures().concise_tasks, truncated: true }));
+    }
+
+    #[test]
+    fn range_scans_are_only_reused_by_the_client_that_ran_them() {
+        let client = || Rc::new(ZkWasmServiceHelper::new(String::new())) as Rc<dyn ZkService>;
+        let scans = RangeScans::default();
+        let zk = client();
+        scans.set(
+            &zk,
+            "inputs".to_string(),
+            Rc::new(RangeScan { tasks: vec![], truncated: false }),
+        );
+        assert!(scans.get(&zk, "inputs").is_some());
+        assert!(scans.get(&zk, "other inputs").is_none());
+        drop(zk);
+        assert!(scans.get(&client(), "inputs").is_none());
+    }
+}
@@ -1,5 +1,6 @@
 mod home;
 pub use home::dashboard::Dashboard;
+mod forms;
 mod navbar;
 pub use navbar::Navbar;
 mod detailed;
@@ -7,3 +8,11 @@ pub use detailed::image::ImageDetails;
 pub use detailed::node::NodeDetails;
 pub use detailed::task::TaskDetails;
 pub use detailed::user::UserDetails;
+mod environment;
+mod provers;
+mod search;
+mod settings;
+mod watch;
+pub use provers::Provers;
+pub use search::SearchResults;
+pub use settings::Settings;
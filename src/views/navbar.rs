@@ -1,43 +1,60 @@
-use crate::utils::web3_subscriber::{ConnectWallet, WalletAccount};
+use crate::utils::preferences::use_preferences;
+use crate::utils::theme::Theme;
+use crate::utils::watch::use_watch_list_provider;
+use crate::utils::web3_subscriber::ConnectWallet;
+use crate::utils::web3_subscriber::WalletAccount;
+use crate::views::environment::EnvironmentSelect;
+use crate::views::forms::AddImageForm;
+use crate::views::forms::AddProveForm;
+use crate::views::watch::WatchTray;
 use crate::Route;
 use dioxus::prelude::*;
 
+/// Switches between light and dark, starting from whatever the current theme resolves to.
+#[component]
+fn ThemeToggle() -> Element {
+    let mut prefs = use_preferences();
+    let (label, next) = match prefs.read().theme.resolve() {
+        Theme::Light => ("Dark Mode", Theme::Dark),
+        _ => ("Light Mode", Theme::Light),
+    };
+    rsx! {
+        button { id: "nav-button", onclick: move |_| prefs.write().theme = next, "{label}" }
+    }
+}
+
 #[component]
 pub fn Navbar() -> Element {
-    let account = use_signal(|| Option::<WalletAccount>::None);
+    let account = use_context_provider(|| Signal::new(Option::<WalletAccount>::None));
     let mut add_image = use_signal(|| false);
     let mut add_prove = use_signal(|| false);
+    use_watch_list_provider();
+    #[cfg(feature = "record")]
+    let recorder = rsx! {
+        button { id: "nav-button", onclick: move |_| crate::utils::mock::save_recording(), "Save Fixtures" }
+    };
+    #[cfg(not(feature = "record"))]
+    let recorder = rsx! {};
     rsx! {
         document::Stylesheet { rel: "stylesheet", href: asset!("./assets/styling/navbar.css") }
         div { id: "navbar",
-            Link { id: "button", to: Route::Dashboard {}, "Home" }
+            Link { id: "button", to: Route::home().href(), "Home" }
+            Link { id: "button", to: Route::Provers { window: String::new() }.href(), "Provers" }
+            Link { id: "button", to: Route::Settings {}, "Settings" }
             div { style: "margin-left: auto; width: fit-content;",
                 button { id: "nav-button", onclick: move |_| add_image.set(true),
                     "Create New Application"
                 }
                 button { id: "nav-button", onclick: move |_| add_prove.set(true), "Submit Prove Task" }
+                {recorder}
+                EnvironmentSelect {}
+                ThemeToggle {}
+                WatchTray {}
                 ConnectWallet { account }
             }
         }
-        // TODO: make these proper components
-        if add_image() {
-            div { class: "popup-task-overlay",
-                div { class: "popup-task",
-                    h2 { "About" }
-                    p { "This is a test navbar with modals." }
-                    button { onclick: move |_| add_image.set(false), "Close" }
-                }
-            }
-        }
-        if add_prove() {
-            div { class: "popup-task-overlay",
-                div { class: "popup-task",
-                    h2 { "Help" }
-                    p { "Here's some help text." }
-                    button { onclick: move |_| add_prove.set(false), "Close" }
-                }
-            }
-        }
+        AddImageForm { open: add_image, account }
+        AddProveForm { open: add_prove, account }
         Outlet::<Route> {}
     }
 }
@@ -0,0 +1,279 @@
+use dioxus::prelude::*;
+use serde::Deserialize;
+use serde::Serialize;
+use std::cmp::Ordering;
+use zkp_service_helper::interface::ProverNode;
+use zkp_service_helper::interface::ProverNodeTimeRange;
+use zkp_service_helper::interface::ProverNodeTimeRangeStats;
+use zkp_service_helper::interface::ProverNodeTimeRangeStatsParams;
+use zkp_service_helper::interface::TimeRangeStats;
+
+use crate::components::table::Table;
+use crate::components::table::TableLike;
+use crate::utils::enum_from_string;
+use crate::utils::enum_label;
+use crate::utils::service::use_zk;
+use crate::utils::service::ServiceFuture;
+use crate::utils::service::ZkService;
+use crate::utils::use_query;
+use crate::utils::webtime_to_rfc3339;
+use crate::utils::AddressKind;
+use crate::utils::AddressStyle;
+use crate::utils::UnwrapOrNA;
+use crate::utils::ZkEntry;
+use crate::views::detailed::node::online_status;
+use crate::views::detailed::node::scan_nodes;
+use crate::views::detailed::node::NodeScan;
+use crate::views::detailed::node::NODE_SCAN_LIMIT;
+use crate::views::settings::EnumSelect;
+use crate::Route;
+use crate::GLOBAL_PADDING;
+
+/// How far back the tasks that provers are ranked on reach.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+enum RankingWindow {
+    #[serde(rename = "24h")]
+    Day,
+    #[default]
+    #[serde(rename = "7d")]
+    Week,
+    #[serde(rename = "30d")]
+    Month,
+}
+
+impl RankingWindow {
+    fn duration(self) -> web_time::Duration {
+        let days = match self {
+            Self::Day => 1,
+            Self::Week => 7,
+            Self::Month => 30,
+        };
+        web_time::Duration::from_secs(days * 24 * 60 * 60)
+    }
+}
+
+/// A node with the outcomes of the tasks it took within the ranking window, if the service had any.
+#[derive(Clone, PartialEq)]
+struct ProverRanking {
+    node: ProverNode,
+    window: Option<TimeRangeStats>,
+}
+
+impl ProverRanking {
+    fn attempted(&self) -> u64 {
+        self.window
+            .as_ref()
+            .map(|it| it.successful + it.failed + it.timed_out)
+            .unwrap_or_default()
+    }
+
+    fn rate(&self, count: impl Fn(&TimeRangeStats) -> u64) -> Option<f64> {
+        let attempted = self.attempted();
+        self.window
+            .as_ref()
+            .filter(|_| attempted > 0)
+            .map(|it| count(it) as f64 / attempted as f64 * 100.0)
+    }
+
+    fn success_rate(&self) -> Option<f64> {
+        self.rate(|it| it.successful)
+    }
+
+    fn timeout_rate(&self) -> Option<f64> {
+        self.rate(|it| it.timed_out)
+    }
+}
+
+/// Best success rate first, more tasks break ties, nodes without tasks in the window go last.
+fn rank(nodes: Vec<ProverNode>, stats: Vec<ProverNodeTimeRangeStats>) -> Vec<ProverRanking> {
+    let mut rankings = nodes
+        .into_iter()
+        .map(|node| {
+            let window = stats
+                .iter()
+                .find(|it| it.address.eq_ignore_ascii_case(&node.address))
+                .map(|it| it.stats.clone());
+            ProverRanking { node, window }
+        })
+        .collect::<Vec<_>>();
+    rankings.sort_by(|a, b| match (a.success_rate(), b.success_rate()) {
+        (Some(x), Some(y)) => y
+            .partial_cmp(&x)
+            .unwrap_or(Ordering::Equal)
+            .then(b.attempted().cmp(&a.attempted())),
+        (x, y) => x.is_none().cmp(&y.is_none()),
+    });
+    rankings
+}
+
+/// The ranked nodes, `truncated` when the node scan stopped at `NODE_SCAN_LIMIT` before listing every node.
+#[derive(Clone, PartialEq)]
+struct Rankings {
+    rankings: Vec<ProverRanking>,
+    truncated: bool,
+}
+
+/// Every node up to `NODE_SCAN_LIMIT`, then the window's stats for all of them in a single request.
+fn query_rankings(zk: &dyn ZkService, window: RankingWindow) -> ServiceFuture<'_, Rankings> {
+    Box::pin(async move {
+        let NodeScan { nodes, truncated } = scan_nodes(zk).await?;
+        if nodes.is_empty() {
+            return Ok(Rankings { rankings: vec![], truncated });
+        }
+
+        let now = web_time::SystemTime::now();
+        let (start, end) = (webtime_to_rfc3339(now - window.duration()), webtime_to_rfc3339(now));
+        let ranges = nodes
+            .iter()
+            .map(|it| ProverNodeTimeRange {
+                address: it.address.clone(),
+                start: start.clone(),
+                end: end.clone(),
+            })
+            .collect();
+        let stats = zk
+            .query_prover_node_timerange_stats(ProverNodeTimeRangeStatsParams { ranges })
+            .await?;
+        Ok(Rankings { rankings: rank(nodes, stats), truncated })
+    })
+}
+
+impl TableLike for Rankings {
+    fn title(&self) -> &str {
+        "Prover Rankings"
+    }
+
+    fn headers(&self) -> Vec<&str> {
+        vec![
+            "Rank",
+            "Node Address",
+            "Success Rate (%)",
+            "Timeout Rate (%)",
+            "Tasks In Window",
+            "Latest Proof Time (s)",
+            "Prover Level",
+            "Version",
+            "Status",
+        ]
+    }
+
+    fn rows(&self) -> Vec<Vec<ZkEntry>> {
+        self.rankings
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let node = &row.node;
+                vec![
+                    ZkEntry::Number((i + 1).to_string()),
+                    ZkEntry::Address(node.address.clone(), AddressStyle::Dashboard, AddressKind::Node),
+                    ZkEntry::Raw(row.success_rate().map(|it| format!("{it:.1}")).unwrap_or_na()),
+                    ZkEntry::Raw(row.timeout_rate().map(|it| format!("{it:.1}")).unwrap_or_na()),
+                    ZkEntry::Number(row.attempted().to_string()),
+                    ZkEntry::Raw(
+                        node.statistics
+                            .proof_timing_stats
+                            .as_ref()
+                            .map(|it| format!("{:.4}", it.latest_time_taken_secs))
+                            .unwrap_or_na(),
+                    ),
+                    ZkEntry::Raw(enum_label(&node.prover_level)),
+                    ZkEntry::Raw(node.version_info.as_ref().map(|it| it.version.clone()).unwrap_or_na()),
+                    ZkEntry::Raw(online_status(node)),
+                ]
+            })
+            .collect()
+    }
+
+    fn row_keys(&self) -> Vec<String> {
+        self.rankings.iter().map(|row| row.node.address.clone()).collect()
+    }
+
+    fn notice(&self) -> Option<String> {
+        self.truncated
+            .then(|| format!("Results truncated, only the first {NODE_SCAN_LIMIT} prover nodes were ranked."))
+    }
+}
+
+/// Every prover node ranked over the window in the URL, so a shared link shows the same ranking.
+#[component]
+pub fn Provers(window: String) -> Element {
+    let window = enum_from_string::<RankingWindow>(&window).unwrap_or_default();
+    let zk = use_zk();
+    let rankings = use_query(use_reactive!(|window| zk.run(move |zk| query_rankings(zk, window))));
+
+    rsx! {
+        div { style: GLOBAL_PADDING,
+            div { id: "detail-header",
+                div { "Prover Leaderboard" }
+                div { id: "right-div",
+                    EnumSelect {
+                        value: window,
+                        options: vec![
+                            (RankingWindow::Day, "Last 24 hours".to_string()),
+                            (RankingWindow::Week, "Last 7 days".to_string()),
+                            (RankingWindow::Month, "Last 30 days".to_string()),
+                        ],
+                        onchange: move |it| {
+                            let window = enum_label(&it);
+                            navigator().push(Route::Provers { window });
+                        },
+                    }
+                }
+            }
+        }
+        {rankings.render(|data| rsx! {
+            Table { data }
+        })}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::fixtures;
+    use crate::testing::minimal;
+    use crate::testing::render;
+    use crate::utils::mock::Fixtures;
+
+    fn render_rankings(fixtures: Fixtures) -> String {
+        let data = Rankings {
+            rankings: rank(fixtures.nodes, fixtures.node_timerange_stats),
+            truncated: false,
+        };
+        render(move || {
+            rsx! {
+                Table { data: data.clone() }
+            }
+        })
+    }
+
+    #[test]
+    fn rankings_order_by_success_rate_then_tasks() {
+        let Fixtures { nodes, .. } = fixtures();
+        let node = |i: usize, address: &str| ProverNode { address: address.to_string(), ..nodes[i].clone() };
+        let stats = |address: &str, successful, failed, timed_out| ProverNodeTimeRangeStats {
+            address: address.to_string(),
+            stats: TimeRangeStats { successful, failed, timed_out },
+        };
+        let ranked = rank(
+            vec![node(0, "0xa"), node(1, "0xb"), node(0, "0xc"), node(1, "0xd")],
+            vec![stats("0xA", 9, 1, 0), stats("0xb", 0, 0, 0), stats("0xc", 18, 1, 1)],
+        );
+        let order = ranked.iter().map(|it| it.node.address.as_str()).collect::<Vec<_>>();
+        assert_eq!(order, ["0xc", "0xa", "0xb", "0xd"]);
+        assert_eq!(ranked[0].success_rate(), Some(90.0));
+        assert_eq!(ranked[0].timeout_rate(), Some(5.0));
+        assert_eq!(ranked[2].success_rate(), None);
+        assert_eq!(ranked[3].attempted(), 0);
+    }
+
+    #[test]
+    fn prover_rankings() {
+        insta::assert_snapshot!(render_rankings(fixtures()));
+    }
+
+    #[test]
+    fn prover_rankings_with_missing_fields() {
+        insta::assert_snapshot!(render_rankings(minimal()));
+    }
+}
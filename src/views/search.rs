@@ -0,0 +1,255 @@
+use dioxus::prelude::*;
+use serde::Serialize;
+use zkp_service_helper::interface::Image;
+use zkp_service_helper::interface::ProverNode;
+use zkp_service_helper::interface::Task;
+use zkp_service_helper::interface::User;
+
+use crate::components::card::EntryListCard;
+use crate::components::card::EntryListLike;
+use crate::utils::enum_label;
+use crate::utils::service::use_zk;
+use crate::utils::service::ZkService;
+use crate::utils::use_query;
+use crate::utils::AddressKind;
+use crate::utils::AddressStyle;
+use crate::utils::ZkEntry;
+use crate::Route;
+use crate::GLOBAL_PADDING;
+
+fn is_hex(it: &str) -> bool {
+    it.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+/// What a query can identify going by its shape alone.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub enum QueryShape {
+    /// A user or a prover node.
+    Address(String),
+    Md5(String),
+    TaskId(String),
+}
+
+impl QueryShape {
+    /// `None` for queries that aren't an address, MD5 or task id.
+    pub fn parse(query: &str) -> Option<Self> {
+        let q = query.trim().to_string();
+        if q.strip_prefix("0x").is_some_and(|it| it.len() == 40 && is_hex(it)) {
+            Some(Self::Address(q))
+        } else if q.len() == 32 && is_hex(&q) {
+            Some(Self::Md5(q))
+        } else if q.len() == 24 && is_hex(&q) {
+            Some(Self::TaskId(q))
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Clone, PartialEq)]
+struct MatchGroup {
+    title: String,
+    entries: Vec<(String, ZkEntry)>,
+}
+
+impl EntryListLike for MatchGroup {
+    type T = ZkEntry;
+
+    fn title(&self) -> String {
+        self.title.clone()
+    }
+
+    fn entries(&self) -> Vec<(&str, Self::T)> {
+        self.entries
+            .iter()
+            .map(|(label, entry)| (label.as_str(), entry.clone()))
+            .collect()
+    }
+}
+
+/// Every entity a search query resolved to, grouped by type.
+#[derive(Clone, PartialEq, Default)]
+pub struct SearchMatches {
+    users: Vec<User>,
+    images: Vec<Image>,
+    tasks: Vec<Task>,
+    nodes: Vec<ProverNode>,
+}
+
+impl SearchMatches {
+    fn count(&self) -> usize {
+        self.users.len() + self.images.len() + self.tasks.len() + self.nodes.len()
+    }
+
+    /// The details page of the only match, otherwise the grouped results page.
+    pub fn route(&self, query: String) -> Route {
+        if self.count() != 1 {
+            return Route::SearchResults { q: query };
+        }
+        if let Some(it) = self.users.first() {
+            Route::UserDetails { id: it.user_address.clone() }
+        } else if let Some(it) = self.images.first() {
+            Route::ImageDetails { id: it.md5.clone() }
+        } else if let Some(it) = self.tasks.first() {
+            Route::TaskDetails { id: it._id.oid.clone() }
+        } else if let Some(it) = self.nodes.first() {
+            Route::NodeDetails { id: it.address.clone() }
+        } else {
+            Route::SearchResults { q: query }
+        }
+    }
+
+    fn groups(&self) -> Vec<MatchGroup> {
+        let group = |title: &str, entries: Vec<(String, ZkEntry)>| MatchGroup {
+            title: format!("{title} ({})", entries.len()),
+            entries,
+        };
+        vec![
+            group(
+                "Users",
+                self.users
+                    .iter()
+                    .map(|it| {
+                        (
+                            "Address".to_string(),
+                            ZkEntry::Address(it.user_address.clone(), AddressStyle::Detailed, AddressKind::User),
+                        )
+                    })
+                    .collect(),
+            ),
+            group(
+                "Images",
+                self.images
+                    .iter()
+                    .map(|it| {
+                        (
+                            it.status.clone(),
+                            ZkEntry::Address(it.md5.clone(), AddressStyle::Detailed, AddressKind::Image),
+                        )
+                    })
+                    .collect(),
+            ),
+            group(
+                "Tasks",
+                self.tasks
+                    .iter()
+                    .map(|it| {
+                        (
+                            format!("{} {}", enum_label(&it.task_type), enum_label(&it.status)),
+                            ZkEntry::Address(it._id.oid.clone(), AddressStyle::Detailed, AddressKind::Task),
+                        )
+                    })
+                    .collect(),
+            ),
+            group(
+                "Prover Nodes",
+                self.nodes
+                    .iter()
+                    .map(|it| {
+                        (
+                            it.performance_track.clone(),
+                            ZkEntry::Address(it.address.clone(), AddressStyle::Detailed, AddressKind::Node),
+                        )
+                    })
+                    .collect(),
+            ),
+        ]
+        .into_iter()
+        .filter(|it| !it.entries.is_empty())
+        .collect()
+    }
+}
+
+async fn resolve_address(zk: &dyn ZkService, address: String) -> anyhow::Result<(Vec<User>, Vec<ProverNode>)> {
+    let (user, nodes) = tokio::join!(
+        zk.query_user(address.clone()),
+        zk.query_node_statistics(Some(address.clone()), None, Some(1)),
+    );
+    let nodes = nodes?
+        .data
+        .into_iter()
+        .filter(|it| it.address.eq_ignore_ascii_case(&address))
+        .collect();
+    Ok((user?.into_iter().collect(), nodes))
+}
+
+/// Looks the query up as every entity type its shape allows,
+/// addresses can be users or prover nodes, MD5s are images and object ids are tasks.
+pub async fn resolve_query(zk: &dyn ZkService, query: &str) -> anyhow::Result<SearchMatches> {
+    let mut matches = SearchMatches::default();
+    match QueryShape::parse(query) {
+        Some(QueryShape::Address(q)) => (matches.users, matches.nodes) = resolve_address(zk, q).await?,
+        Some(QueryShape::Md5(q)) => matches.images = zk.query_image(q).await?.into_iter().collect(),
+        Some(QueryShape::TaskId(q)) => matches.tasks = zk.query_task_from_id(q).await?.into_iter().collect(),
+        None => {}
+    }
+    Ok(matches)
+}
+
+#[component]
+pub fn SearchResults(q: String) -> Element {
+    tracing::info!("Search results loading {q}");
+
+    let zk = use_zk();
+    let query = q.clone();
+    let matches = use_query(use_reactive!(|query| {
+        let zk = zk.get();
+        async move { resolve_query(&*zk, &query).await }
+    }));
+
+    rsx! {
+        div { style: GLOBAL_PADDING,
+            div { id: "detail-header",
+                div { "Search results" }
+                div { id: "right-div", "{q}" }
+            }
+        }
+        {matches.render(|found| if found.count() == 0 {
+            rsx! {
+                div { style: GLOBAL_PADDING,
+                    p { "No users, images, tasks or prover nodes match '{q}'." }
+                    p { "Search by a 0x address, a 32 character image MD5 or a 24 character task ID." }
+                }
+            }
+        } else {
+            rsx! {
+                {
+                    found
+                        .groups()
+                        .into_iter()
+                        .map(|data| rsx! {
+                            div { class: "node-details-wrapper",
+                                EntryListCard {
+                                    data,
+                                    card_class: "transparent-border",
+                                    header_class: "node-details-header",
+                                    lcol_class: "node-details-col",
+                                }
+                            }
+                        })
+                }
+            }
+        })}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_shapes() {
+        let address = "0x91c4e7a2b5d8f03e6a9c1b4d7e0f3a6c9b2e5d81";
+        let md5 = "3f9a1c7e5b2d4f6a8c0e1b3d5f7a9c2e";
+        let id = "66f1a2b3c4d5e6f7a8b9c000";
+        assert_eq!(
+            QueryShape::parse(&format!(" {address} ")),
+            Some(QueryShape::Address(address.to_string()))
+        );
+        assert_eq!(QueryShape::parse(md5), Some(QueryShape::Md5(md5.to_string())));
+        assert_eq!(QueryShape::parse(id), Some(QueryShape::TaskId(id.to_string())));
+        assert_eq!(QueryShape::parse(""), None);
+        assert_eq!(QueryShape::parse("0x91c4"), None);
+        assert_eq!(QueryShape::parse("zz9a1c7e5b2d4f6a8c0e1b3d5f7a9c2e"), None);
+    }
+}
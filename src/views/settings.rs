@@ -0,0 +1,215 @@
+use dioxus::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::components::card::Card;
+use crate::components::table::PAGE_SIZES;
+use crate::utils::download::download_bytes;
+use crate::utils::enum_from_string;
+use crate::utils::enum_label;
+use crate::utils::environment::use_environments;
+use crate::utils::preferences::use_preferences;
+use crate::utils::preferences::NumberFormat;
+use crate::utils::preferences::Preferences;
+use crate::utils::theme::Theme;
+use crate::utils::DateFormat;
+use crate::utils::DisplayTimeZone;
+use crate::GLOBAL_PADDING;
+
+/// A select over the variants of a unit enum, keyed by their serialized names.
+#[component]
+pub(super) fn EnumSelect<T: Serialize + DeserializeOwned + PartialEq + Clone + 'static>(
+    value: T,
+    options: Vec<(T, String)>,
+    onchange: EventHandler<T>,
+) -> Element {
+    rsx! {
+        select {
+            value: enum_label(&value),
+            onchange: move |evt| {
+                if let Ok(it) = enum_from_string(&evt.value()) {
+                    onchange.call(it);
+                }
+            },
+            {
+                options
+                    .into_iter()
+                    .map(|(it, label)| {
+                        let value = enum_label(&it);
+                        rsx! {
+                            option { key: "{value}", value: "{value}", "{label}" }
+                        }
+                    })
+            }
+        }
+    }
+}
+
+#[component]
+fn Setting(label: String, description: Option<String>, children: Element) -> Element {
+    rsx! {
+        div { class: "setting",
+            div {
+                div { class: "setting-label", "{label}" }
+                if let Some(description) = description {
+                    div { class: "setting-description", "{description}" }
+                }
+            }
+            div { class: "setting-control", {children} }
+        }
+    }
+}
+
+#[component]
+pub fn Settings() -> Element {
+    let mut prefs = use_preferences();
+    let mut envs = use_environments();
+    let mut import_error = use_signal(|| Option::<String>::None);
+    let current = prefs();
+    let environment = envs.current();
+    let environments = envs.config.read().environments.clone();
+
+    let export = move |_| {
+        let res = prefs.peek().to_json().and_then(|json| {
+            download_bytes("zkp-web-gui-settings.json", json.as_bytes(), "application/json")
+                .map_err(|e| anyhow::anyhow!("Failed to save file: {e:?}"))
+        });
+        if let Err(e) = res {
+            tracing::error!("{e}");
+        }
+    };
+
+    let display = rsx! {
+        Setting { label: "Default page size", description: "Rows per page paginated tables show, a size picked in a table lasts until you leave the page",
+            select {
+                value: current.page_size.map(|it| it.to_string()).unwrap_or_default(),
+                onchange: move |evt| prefs.write().page_size = evt.value().parse().ok(),
+                option { value: "", "Each table's own" }
+                for size in PAGE_SIZES {
+                    option { key: "{size}", value: "{size}", "{size}" }
+                }
+            }
+        }
+        Setting { label: "Number format",
+            EnumSelect {
+                value: current.number_format,
+                options: vec![
+                    (NumberFormat::Plain, "Plain (1234567)".to_string()),
+                    (NumberFormat::Grouped, "Grouped (1,234,567)".to_string()),
+                ],
+                onchange: move |it| prefs.write().number_format = it,
+            }
+        }
+        Setting { label: "Theme",
+            EnumSelect {
+                value: current.theme,
+                options: vec![
+                    (Theme::System, "System".to_string()),
+                    (Theme::Light, "Light".to_string()),
+                    (Theme::Dark, "Dark".to_string()),
+                ],
+                onchange: move |it| prefs.write().theme = it,
+            }
+        }
+    };
+
+    let time = rsx! {
+        Setting { label: "Time zone",
+            EnumSelect {
+                value: current.time.time_zone,
+                options: vec![
+                    (DisplayTimeZone::Local, "Local".to_string()),
+                    (DisplayTimeZone::Utc, "UTC".to_string()),
+                ],
+                onchange: move |it| prefs.write().time.time_zone = it,
+            }
+        }
+        Setting { label: "Date format",
+            EnumSelect {
+                value: current.time.date_format,
+                options: vec![
+                    (DateFormat::Regional, "Regional (23/09/2024 2:45 pm)".to_string()),
+                    (DateFormat::Iso, "ISO (2024-09-23 14:45)".to_string()),
+                ],
+                onchange: move |it| prefs.write().time.date_format = it,
+            }
+        }
+        Setting { label: "Relative times", description: "Show \"4 min ago\", with the full time on hover",
+            input {
+                r#type: "checkbox",
+                checked: current.time.relative,
+                onchange: move |evt| prefs.write().time.relative = evt.checked(),
+            }
+        }
+    };
+
+    let service = rsx! {
+        Setting { label: "Environment", description: "{environment.url}",
+            select {
+                value: "{environment.name}",
+                onchange: move |evt| envs.select(evt.value()),
+                for it in environments {
+                    option { key: "{it.name}", value: "{it.name}", "{it.name}" }
+                }
+            }
+        }
+        Setting {
+            label: "Hide private inputs",
+            description: "Keep task witnesses off screen, e.g. when sharing it",
+            input {
+                r#type: "checkbox",
+                checked: current.hide_private_inputs,
+                onchange: move |evt| prefs.write().hide_private_inputs = evt.checked(),
+            }
+        }
+    };
+
+    rsx! {
+        div { style: GLOBAL_PADDING,
+            div { id: "detail-header",
+                div { "Settings" }
+                div { id: "right-div",
+                    button { class: "settings-button", onclick: export, "Export" }
+                    label { class: "settings-button",
+                        "Import"
+                        input {
+                            r#type: "file",
+                            accept: ".json",
+                            style: "display: none;",
+                            onchange: move |evt| async move {
+                                let Some(engine) = evt.files() else {
+                                    return;
+                                };
+                                let Some(name) = engine.files().first().cloned() else {
+                                    return;
+                                };
+                                let res = match engine.read_file(&name).await {
+                                    Some(raw) => Preferences::from_json(&raw),
+                                    None => Err(anyhow::anyhow!("Couldn't read {name}")),
+                                };
+                                match res {
+                                    Ok(it) => {
+                                        prefs.set(it);
+                                        import_error.set(None);
+                                    }
+                                    Err(e) => import_error.set(Some(e.to_string())),
+                                }
+                            },
+                        }
+                    }
+                    button { class: "settings-button", onclick: move |_| prefs.set(Preferences::default()),
+                        "Reset"
+                    }
+                }
+            }
+            if let Some(e) = import_error() {
+                div { class: "error-card", "{e}" }
+            }
+            div { class: "settings",
+                Card { header: "Display", body: display }
+                Card { header: "Timestamps", body: time }
+                Card { header: "Service", body: service }
+            }
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use dioxus::prelude::*;
+use zkp_service_helper::interface::TaskStatus;
+
+use crate::components::card::EntryLike;
+use crate::utils::watch::use_watch_list;
+use crate::utils::AddressKind;
+use crate::utils::AddressStyle;
+use crate::utils::ZkEntry;
+
+#[component]
+pub fn WatchTray() -> Element {
+    let mut list = use_watch_list();
+    let mut open = use_signal(|| false);
+    let active = list.0.read().iter().filter(|it| it.is_active()).count();
+    let total = list.0.read().len();
+
+    rsx! {
+        div { class: "watch-tray",
+            button { id: "nav-button", onclick: move |_| open.toggle(),
+                "Watching {active}/{total}"
+            }
+            if open() {
+                div { class: "watch-tray-items",
+                    if total == 0 {
+                        div { class: "watch-tray-empty", "No watched tasks" }
+                    }
+                    {
+                        list.0
+                            .read()
+                            .clone()
+                            .into_iter()
+                            .map(|it| {
+                                let id = it.id.clone();
+                                rsx! {
+                                    div { key: "{it.id}", class: "watch-tray-item",
+                                        {ZkEntry::Address(it.id.clone(), AddressStyle::Dashboard, AddressKind::Task).into_cell()}
+                                        {ZkEntry::TaskStatus(it.status.clone()).into_cell()}
+                                        button { onclick: move |_| list.unwatch(&id), "Remove" }
+                                    }
+                                }
+                            })
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[component]
+pub fn WatchButton(id: String, status: TaskStatus) -> Element {
+    let mut list = use_watch_list();
+    let watching = list.contains(&id);
+    rsx! {
+        button {
+            class: "action-button",
+            onclick: move |_| {
+                if watching {
+                    list.unwatch(&id);
+                } else {
+                    list.watch(id.clone(), status.clone());
+                }
+            },
+            if watching {
+                "Unwatch"
+            } else {
+                "Watch"
+            }
+        }
+    }
+}